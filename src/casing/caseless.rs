@@ -0,0 +1,105 @@
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{CaseMapper, NormalizationForm, Normalizer};
+
+// сравнение строк без учёта регистра, The Unicode Standard, 3.13:
+//  * D145, canonical caseless: NFD(toCasefold(NFD(X)))
+//  * D146, compatibility caseless: NFKD(toCasefold(NFKD(toCasefold(NFD(X)))))
+//  * D147, identifier caseless: toNFKC_Casefold(NFD(X))
+//
+// внутренняя NFD нужна только из-за U+0345 COMBINING GREEK YPOGEGRAMMENI: CCC 240, но свёртка
+// регистра даёт стартер U+03B9, и порядок нестартеров перед ним после свёртки уже не восстановить.
+// поэтому она выполняется, только если строка содержит U+0345 или кодпоинты с U+0345 в декомпозиции
+
+/// COMBINING GREEK YPOGEGRAMMENI
+const YPOGEGRAMMENI: u32 = 0x0345;
+
+/// канонически эквивалентны ли строки без учёта регистра (D145)
+#[cfg(feature = "embedded")]
+pub fn canonical_caseless_eq(a: &str, b: &str) -> bool
+{
+    CaseMapper::new(UnicodeVersion::default().dataset()).canonical_caseless_eq(a, b)
+}
+
+/// эквивалентны ли строки по совместимости без учёта регистра (D146)
+#[cfg(feature = "embedded")]
+pub fn compatibility_caseless_eq(a: &str, b: &str) -> bool
+{
+    CaseMapper::new(UnicodeVersion::default().dataset()).compatibility_caseless_eq(a, b)
+}
+
+/// совпадают ли идентификаторы без учёта регистра (D147)
+#[cfg(feature = "embedded")]
+pub fn identifier_caseless_eq(a: &str, b: &str) -> bool
+{
+    CaseMapper::new(UnicodeVersion::default().dataset()).identifier_caseless_eq(a, b)
+}
+
+impl CaseMapper<'_>
+{
+    /// ключ canonical caseless сравнения: NFD(toCasefold(NFD(X)))
+    pub fn canonical_caseless_key(&self, text: &str) -> String
+    {
+        let folded = self.case_fold(&self.ypogegrammeni_nfd(text));
+
+        self.normalize(&folded, NormalizationForm::Nfd)
+    }
+
+    /// ключ compatibility caseless сравнения: NFKD(toCasefold(NFKD(toCasefold(NFD(X)))))
+    pub fn compatibility_caseless_key(&self, text: &str) -> String
+    {
+        let folded = self.case_fold(&self.ypogegrammeni_nfd(text));
+        let folded = self.case_fold(&self.normalize(&folded, NormalizationForm::Nfkd));
+
+        self.normalize(&folded, NormalizationForm::Nfkd)
+    }
+
+    /// ключ identifier caseless сравнения: toNFKC_Casefold(NFD(X))
+    pub fn identifier_caseless_key(&self, text: &str) -> String
+    {
+        // toNFKC_Casefold начинается с NFD
+        self.dataset.nfkc_casefold(text)
+    }
+
+    /// канонически эквивалентны ли строки без учёта регистра (D145)
+    pub fn canonical_caseless_eq(&self, a: &str, b: &str) -> bool
+    {
+        self.canonical_caseless_key(a) == self.canonical_caseless_key(b)
+    }
+
+    /// эквивалентны ли строки по совместимости без учёта регистра (D146)
+    pub fn compatibility_caseless_eq(&self, a: &str, b: &str) -> bool
+    {
+        self.compatibility_caseless_key(a) == self.compatibility_caseless_key(b)
+    }
+
+    /// совпадают ли идентификаторы без учёта регистра (D147)
+    pub fn identifier_caseless_eq(&self, a: &str, b: &str) -> bool
+    {
+        self.identifier_caseless_key(a) == self.identifier_caseless_key(b)
+    }
+
+    /// NFD строки, если в ней (или в декомпозициях её кодпоинтов) есть U+0345
+    fn ypogegrammeni_nfd(&self, text: &str) -> String
+    {
+        let nfd = self.dataset.nfd();
+
+        let needed = text.chars().map(u32::from).any(|code| {
+            code == YPOGEGRAMMENI
+                || nfd.get(&code).is_some_and(|decomposition| {
+                    decomposition.iter().any(|c| c.code == YPOGEGRAMMENI)
+                })
+        });
+
+        match needed {
+            true => self.normalize(text, NormalizationForm::Nfd),
+            false => text.to_owned(),
+        }
+    }
+
+    /// нормализация строки набором данных преобразования
+    fn normalize(&self, text: &str, form: NormalizationForm) -> String
+    {
+        Normalizer::new(self.dataset, form).normalize(text)
+    }
+}
@@ -0,0 +1,294 @@
+use crate::codepoint::{BinaryProperty, SimpleCaseMapping};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{
+    CaseFoldingStatus, CasingCondition, CasingContext, Dataset, SpecialCasing,
+};
+
+mod caseless;
+
+#[cfg(feature = "embedded")]
+pub use caseless::*;
+
+// преобразование регистра строк, The Unicode Standard, 3.13 (Default Case Algorithms):
+//  * отображение кодпоинта - из SpecialCasing.txt, если условия записи выполняются (сначала записи
+//    языка преобразования, затем общие), иначе - простое отображение из UnicodeData.txt
+//  * свёртка регистра - CaseFolding.txt: полная (C + F) или простая (C + S), для tr / az - T
+//  * заглавные буквы: в каждом сегменте между границами слов (UAX #29) первая буква с регистром -
+//    Titlecase, следующие за ней кодпоинты - Lowercase, предшествующие ей - без изменений
+
+/// вид отображения регистра
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case
+{
+    Lower,
+    Title,
+    Upper,
+}
+
+/// преобразование регистра строк с учётом языка
+#[derive(Debug, Clone)]
+pub struct CaseMapper<'a>
+{
+    dataset: &'a Dataset,
+    language: Option<String>,
+}
+
+/// строчные буквы (toLowercase)
+#[cfg(feature = "embedded")]
+pub fn to_lower(text: &str) -> String
+{
+    CaseMapper::new(UnicodeVersion::default().dataset()).to_lower(text)
+}
+
+/// прописные буквы (toUppercase)
+#[cfg(feature = "embedded")]
+pub fn to_upper(text: &str) -> String
+{
+    CaseMapper::new(UnicodeVersion::default().dataset()).to_upper(text)
+}
+
+/// первые буквы слов - заглавные, остальные - строчные (toTitlecase)
+#[cfg(feature = "embedded")]
+pub fn to_title(text: &str) -> String
+{
+    CaseMapper::new(UnicodeVersion::default().dataset()).to_title(text)
+}
+
+/// полная свёртка регистра (toCasefold)
+#[cfg(feature = "embedded")]
+pub fn case_fold(text: &str) -> String
+{
+    CaseMapper::new(UnicodeVersion::default().dataset()).case_fold(text)
+}
+
+impl<'a> CaseMapper<'a>
+{
+    /// преобразование регистра без учёта языка
+    pub fn new(dataset: &'a Dataset) -> Self
+    {
+        Self {
+            dataset,
+            language: None,
+        }
+    }
+
+    /// задать язык (BCP 47, учитывается основной тег): tr, az, lt - особые правила
+    pub fn language(mut self, language: &str) -> Self
+    {
+        let primary = language.split(['-', '_']).next().unwrap_or_default();
+
+        self.language = Some(primary.to_ascii_lowercase());
+        self
+    }
+
+    /// строчные буквы
+    pub fn to_lower(&self, text: &str) -> String
+    {
+        let codes = codes(text);
+
+        collect((0 .. codes.len()).flat_map(|i| self.map(&codes, i, Case::Lower)))
+    }
+
+    /// прописные буквы
+    pub fn to_upper(&self, text: &str) -> String
+    {
+        let codes = codes(text);
+
+        collect((0 .. codes.len()).flat_map(|i| self.map(&codes, i, Case::Upper)))
+    }
+
+    /// первая буква с регистром каждого слова (границы слов - UAX #29) - заглавная, следующие за
+    /// ней - строчные
+    pub fn to_title(&self, text: &str) -> String
+    {
+        let codes = codes(text);
+        let mut result = vec![];
+        let mut start = 0;
+
+        for word in self.dataset.words(text) {
+            let end = start + word.chars().count();
+
+            // кодпоинты до первой буквы с регистром не изменяются
+            let first = (start .. end)
+                .find(|&i| self.dataset.is_cased(codes[i]))
+                .unwrap_or(end);
+
+            result.extend(&codes[start .. first]);
+
+            if first < end {
+                result.extend(self.map(&codes, first, Case::Title));
+                result.extend((first + 1 .. end).flat_map(|i| self.map(&codes, i, Case::Lower)));
+            }
+
+            start = end;
+        }
+
+        collect(result)
+    }
+
+    /// полная свёртка регистра
+    pub fn case_fold(&self, text: &str) -> String
+    {
+        collect(text.chars().flat_map(|c| self.fold(u32::from(c), true)))
+    }
+
+    /// простая свёртка регистра - длина строки в кодпоинтах не меняется
+    pub fn simple_case_fold(&self, text: &str) -> String
+    {
+        collect(text.chars().flat_map(|c| self.fold(u32::from(c), false)))
+    }
+
+    /// отображение кодпоинта строки
+    fn map(&self, codes: &[u32], i: usize, case: Case) -> Vec<u32>
+    {
+        let code = codes[i];
+
+        if let Some(casing) = self.special_casing(codes, i) {
+            return match case {
+                Case::Lower => casing.lower.clone(),
+                Case::Title => casing.title.clone(),
+                Case::Upper => casing.upper.clone(),
+            };
+        }
+
+        let codepoint = match self.dataset.unicode().get(&code) {
+            Some(codepoint) => codepoint,
+            None => return vec![code],
+        };
+
+        let mapping = match case {
+            Case::Lower => codepoint.simple_lowercase_mapping,
+            Case::Upper => codepoint.simple_uppercase_mapping,
+            // пустое поле Simple_Titlecase_Mapping - совпадает с Simple_Uppercase_Mapping
+            Case::Title => match codepoint.simple_titlecase_mapping {
+                SimpleCaseMapping::None => codepoint.simple_uppercase_mapping,
+                mapping => mapping,
+            },
+        };
+
+        match mapping {
+            SimpleCaseMapping::Some(mapped) => vec![mapped],
+            SimpleCaseMapping::None => vec![code],
+        }
+    }
+
+    /// запись SpecialCasing.txt, условия которой выполняются: сначала записи языка, затем общие
+    fn special_casing(&self, codes: &[u32], i: usize) -> Option<&'a SpecialCasing>
+    {
+        let entries = self.dataset.special_casing().get(&codes[i])?;
+
+        let language = entries
+            .iter()
+            .filter(|casing| casing.language.is_some() && casing.language == self.language);
+        let common = entries.iter().filter(|casing| casing.language.is_none());
+
+        language.chain(common).find(|casing| {
+            casing
+                .conditions
+                .iter()
+                .all(|condition| self.satisfies(codes, i, condition))
+        })
+    }
+
+    /// выполняется ли условие для кодпоинта строки
+    fn satisfies(&self, codes: &[u32], i: usize, condition: &CasingCondition) -> bool
+    {
+        let dataset = self.dataset;
+        let ccc = |code: u32| dataset.unicode().get(&code).map_or(0, |c| c.ccc.u8());
+        let (before, after) = (&codes[.. i], &codes[i + 1 ..]);
+
+        let result = match condition.context {
+            CasingContext::FinalSigma => {
+                let mut before = before
+                    .iter()
+                    .rev()
+                    .filter(|&&code| !dataset.is_case_ignorable(code));
+                let mut after = after
+                    .iter()
+                    .filter(|&&code| !dataset.is_case_ignorable(code));
+
+                before.next().is_some_and(|&code| dataset.is_cased(code))
+                    && !after.next().is_some_and(|&code| dataset.is_cased(code))
+            }
+            CasingContext::AfterSoftDotted => before
+                .iter()
+                .rev()
+                .find(|&&code| matches!(ccc(code), 0 | 230))
+                .is_some_and(|&code| dataset.has_property(code, BinaryProperty::SoftDotted)),
+            CasingContext::MoreAbove => after
+                .iter()
+                .find(|&&code| matches!(ccc(code), 0 | 230))
+                .is_some_and(|&code| ccc(code) == 230),
+            CasingContext::BeforeDot => after
+                .iter()
+                .find(|&&code| code == 0x0307 || matches!(ccc(code), 0 | 230))
+                .is_some_and(|&code| code == 0x0307),
+            CasingContext::AfterI => before
+                .iter()
+                .rev()
+                .find(|&&code| matches!(ccc(code), 0 | 230))
+                .is_some_and(|&code| code == 0x0049),
+        };
+
+        result != condition.negated
+    }
+
+    /// свёртка регистра кодпоинта
+    fn fold(&self, code: u32, full: bool) -> Vec<u32>
+    {
+        let foldings = match self.dataset.case_folding().get(&code) {
+            Some(foldings) => foldings,
+            None => return vec![code],
+        };
+
+        let turkic = matches!(self.language.as_deref(), Some("tr" | "az"));
+
+        let find = |status: CaseFoldingStatus| {
+            foldings
+                .iter()
+                .find(|folding| folding.status == status)
+                .map(|folding| folding.mapping.clone())
+        };
+
+        let specific = match full {
+            true => CaseFoldingStatus::Full,
+            false => CaseFoldingStatus::Simple,
+        };
+
+        turkic
+            .then(|| find(CaseFoldingStatus::Turkic))
+            .flatten()
+            .or_else(|| find(CaseFoldingStatus::Common))
+            .or_else(|| find(specific))
+            .unwrap_or(vec![code])
+    }
+
+}
+
+impl Dataset
+{
+    /// буква с регистром (Cased)
+    pub fn is_cased(&self, code: u32) -> bool
+    {
+        self.has_property(code, BinaryProperty::Cased)
+    }
+
+    /// кодпоинт игнорируется при определении контекста регистра (Case_Ignorable)
+    pub fn is_case_ignorable(&self, code: u32) -> bool
+    {
+        self.has_property(code, BinaryProperty::CaseIgnorable)
+    }
+}
+
+/// кодпоинты строки
+fn codes(text: &str) -> Vec<u32>
+{
+    text.chars().map(u32::from).collect()
+}
+
+/// строка из кодпоинтов
+fn collect(codes: impl IntoIterator<Item = u32>) -> String
+{
+    codes.into_iter().filter_map(char::from_u32).collect()
+}
@@ -129,7 +129,7 @@ impl TryFrom<&str> for BidiClass
             "RLI" => Self::RightToLeftIsolate,
             "FSI" => Self::FirstStrongIsolate,
             "PDI" => Self::PopDirectionalIsolate,
-            _ => return Err(PropertiesError::UnknownPropertyValue),
+            _ => return Err(PropertiesError::unknown("Bidi_Class", abbr)),
         })
     }
 }
@@ -142,7 +142,7 @@ impl TryFrom<u8> for BidiClass
     fn try_from(value: u8) -> Result<Self, Self::Error>
     {
         if value == 0 || value == 11 || value > 24 {
-            return Err(PropertiesError::UnknownPropertyValue);
+            return Err(PropertiesError::unknown("Bidi_Class", value));
         }
 
         Ok(unsafe { core::mem::transmute::<u8, BidiClass>(value) })
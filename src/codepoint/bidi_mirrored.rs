@@ -31,7 +31,7 @@ impl TryFrom<&str> for BidiMirrored
         Ok(match value {
             "Y" => Self(true),
             "N" => Self(false),
-            _ => return Err(PropertiesError::UnknownPropertyValue),
+            _ => return Err(PropertiesError::unknown("Bidi_Mirrored", value)),
         })
     }
 }
@@ -0,0 +1,162 @@
+use super::PropertiesError;
+use crate::SourceFile;
+
+/// перечисление бинарных свойств: вариант = короткое название, длинное название
+macro_rules! binary_properties {
+    ($($variant:ident = $abbr:literal, $name:literal;)*) => {
+        /// бинарное свойство кодпоинта из PropList.txt, DerivedCoreProperties.txt и emoji-data.txt
+        /// названия - из PropertyAliases.txt: короткое и длинное
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum BinaryProperty
+        {
+            $($variant,)*
+        }
+
+        impl BinaryProperty
+        {
+            /// все свойства
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            /// длинное название, например White_Space
+            pub fn as_str(&self) -> &'static str
+            {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            /// короткое название, например WSpace
+            pub fn abbr(&self) -> &'static str
+            {
+                match self {
+                    $(Self::$variant => $abbr,)*
+                }
+            }
+        }
+    };
+}
+
+binary_properties! {
+    AsciiHexDigit = "AHex", "ASCII_Hex_Digit";
+    Alphabetic = "Alpha", "Alphabetic";
+    BidiControl = "Bidi_C", "Bidi_Control";
+    CaseIgnorable = "CI", "Case_Ignorable";
+    Cased = "Cased", "Cased";
+    ChangesWhenCasefolded = "CWCF", "Changes_When_Casefolded";
+    ChangesWhenCasemapped = "CWCM", "Changes_When_Casemapped";
+    ChangesWhenLowercased = "CWL", "Changes_When_Lowercased";
+    ChangesWhenTitlecased = "CWT", "Changes_When_Titlecased";
+    ChangesWhenUppercased = "CWU", "Changes_When_Uppercased";
+    Dash = "Dash", "Dash";
+    DefaultIgnorableCodePoint = "DI", "Default_Ignorable_Code_Point";
+    Deprecated = "Dep", "Deprecated";
+    Diacritic = "Dia", "Diacritic";
+    Emoji = "Emoji", "Emoji";
+    EmojiComponent = "EComp", "Emoji_Component";
+    EmojiModifier = "EMod", "Emoji_Modifier";
+    EmojiModifierBase = "EBase", "Emoji_Modifier_Base";
+    EmojiPresentation = "EPres", "Emoji_Presentation";
+    ExtendedPictographic = "ExtPict", "Extended_Pictographic";
+    Extender = "Ext", "Extender";
+    GraphemeBase = "Gr_Base", "Grapheme_Base";
+    GraphemeExtend = "Gr_Ext", "Grapheme_Extend";
+    GraphemeLink = "Gr_Link", "Grapheme_Link";
+    HexDigit = "Hex", "Hex_Digit";
+    Hyphen = "Hyphen", "Hyphen";
+    IdCompatMathContinue = "ID_Compat_Math_Continue", "ID_Compat_Math_Continue";
+    IdCompatMathStart = "ID_Compat_Math_Start", "ID_Compat_Math_Start";
+    IdContinue = "IDC", "ID_Continue";
+    IdStart = "IDS", "ID_Start";
+    IdsBinaryOperator = "IDSB", "IDS_Binary_Operator";
+    IdsTrinaryOperator = "IDST", "IDS_Trinary_Operator";
+    IdsUnaryOperator = "IDSU", "IDS_Unary_Operator";
+    Ideographic = "Ideo", "Ideographic";
+    JoinControl = "Join_C", "Join_Control";
+    LogicalOrderException = "LOE", "Logical_Order_Exception";
+    Lowercase = "Lower", "Lowercase";
+    Math = "Math", "Math";
+    ModifierCombiningMark = "MCM", "Modifier_Combining_Mark";
+    NoncharacterCodePoint = "NChar", "Noncharacter_Code_Point";
+    OtherAlphabetic = "OAlpha", "Other_Alphabetic";
+    OtherDefaultIgnorableCodePoint = "ODI", "Other_Default_Ignorable_Code_Point";
+    OtherGraphemeExtend = "OGr_Ext", "Other_Grapheme_Extend";
+    OtherIdContinue = "OIDC", "Other_ID_Continue";
+    OtherIdStart = "OIDS", "Other_ID_Start";
+    OtherLowercase = "OLower", "Other_Lowercase";
+    OtherMath = "OMath", "Other_Math";
+    OtherUppercase = "OUpper", "Other_Uppercase";
+    PatternSyntax = "Pat_Syn", "Pattern_Syntax";
+    PatternWhiteSpace = "Pat_WS", "Pattern_White_Space";
+    PrependedConcatenationMark = "PCM", "Prepended_Concatenation_Mark";
+    QuotationMark = "QMark", "Quotation_Mark";
+    Radical = "Radical", "Radical";
+    RegionalIndicator = "RI", "Regional_Indicator";
+    SentenceTerminal = "STerm", "Sentence_Terminal";
+    SoftDotted = "SD", "Soft_Dotted";
+    TerminalPunctuation = "Term", "Terminal_Punctuation";
+    UnifiedIdeograph = "UIdeo", "Unified_Ideograph";
+    Uppercase = "Upper", "Uppercase";
+    VariationSelector = "VS", "Variation_Selector";
+    WhiteSpace = "WSpace", "White_Space";
+    XidContinue = "XIDC", "XID_Continue";
+    XidStart = "XIDS", "XID_Start";
+}
+
+impl BinaryProperty
+{
+    /// файл-источник, в котором перечислены кодпоинты свойства
+    pub fn source(&self) -> SourceFile
+    {
+        match self {
+            Self::Alphabetic
+            | Self::CaseIgnorable
+            | Self::Cased
+            | Self::ChangesWhenCasefolded
+            | Self::ChangesWhenCasemapped
+            | Self::ChangesWhenLowercased
+            | Self::ChangesWhenTitlecased
+            | Self::ChangesWhenUppercased
+            | Self::DefaultIgnorableCodePoint
+            | Self::GraphemeBase
+            | Self::GraphemeExtend
+            | Self::GraphemeLink
+            | Self::IdContinue
+            | Self::IdStart
+            | Self::Lowercase
+            | Self::Math
+            | Self::Uppercase
+            | Self::XidContinue
+            | Self::XidStart => SourceFile::DerivedCoreProperties,
+            Self::Emoji
+            | Self::EmojiComponent
+            | Self::EmojiModifier
+            | Self::EmojiModifierBase
+            | Self::EmojiPresentation
+            | Self::ExtendedPictographic => SourceFile::EmojiData,
+            _ => SourceFile::PropList,
+        }
+    }
+}
+
+impl TryFrom<&str> for BinaryProperty
+{
+    type Error = PropertiesError;
+
+    /// по короткому или длинному названию
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        Self::ALL
+            .iter()
+            .find(|property| property.abbr() == name || property.as_str() == name)
+            .copied()
+            .ok_or_else(|| PropertiesError::unknown("Binary_Property", name))
+    }
+}
+
+impl core::fmt::Display for BinaryProperty
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.write_str(self.as_str())
+    }
+}
@@ -1,9 +1,12 @@
+#[cfg(feature = "embedded")]
 use std::collections::HashMap;
 
+#[cfg(feature = "embedded")]
 use crate::parse::UNICODE;
 
 use super::PropertiesError;
 
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// сжатые значения CCC
     pub static ref COMPRESED_CCC: HashMap<u8, u8> = compressed_ccc();
@@ -66,6 +69,7 @@ impl CanonicalCombiningClass
     /// в сжатом до 6 бит виде - берём все CCC, используемые в UCD, сортируем их по возрастанию,
     /// возвращаем индекс элемента этого массива
     #[inline]
+    #[cfg(feature = "embedded")]
     pub fn compressed(&self) -> u8
     {
         COMPRESED_CCC[&self.0]
@@ -104,11 +108,15 @@ impl TryFrom<&str> for CanonicalCombiningClass
 
     fn try_from(value: &str) -> Result<Self, Self::Error>
     {
-        Ok(Self::from(value.parse::<u8>()?))
+        match value.parse::<u8>() {
+            Ok(ccc) => Ok(Self::from(ccc)),
+            Err(_) => Err(PropertiesError::unknown("Canonical_Combining_Class", value)),
+        }
     }
 }
 
 /// хешмап сжатых значений CCC
+#[cfg(feature = "embedded")]
 fn compressed_ccc() -> HashMap<u8, u8>
 {
     let mut ccc_list = vec![];
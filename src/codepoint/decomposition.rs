@@ -18,7 +18,7 @@ impl TryFrom<&str> for Decomposition
     fn try_from(value: &str) -> Result<Self, Self::Error>
     {
         let (tag_string, decomposition_string) = match value.starts_with('<') {
-            true => value.split_once(' ').unwrap(),
+            true => value.split_once(' ').unwrap_or((value, "")),
             false => ("", value),
         };
 
@@ -27,10 +27,11 @@ impl TryFrom<&str> for Decomposition
             false => None,
         };
 
-        let codes: Vec<u32> = decomposition_string
+        let codes = decomposition_string
             .split_whitespace()
-            .map(|v| u32::from_str_radix(v, 16).unwrap())
-            .collect();
+            .map(|v| u32::from_str_radix(v, 16))
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|_| PropertiesError::unknown("Decomposition_Mapping", decomposition_string))?;
 
         Ok(Self { codes, tag })
     }
@@ -101,7 +102,7 @@ impl TryFrom<&str> for DecompositionTag
             "<square>" => Self::Square,
             "<fraction>" => Self::Fraction,
             "<compat>" => Self::Compat,
-            _ => return Err(PropertiesError::UnknownPropertyValue),
+            _ => return Err(PropertiesError::unknown("Decomposition_Type", abbr)),
         })
     }
 }
@@ -113,9 +114,9 @@ impl TryFrom<u8> for DecompositionTag
     #[inline]
     fn try_from(value: u8) -> Result<Self, Self::Error>
     {
-        match value & 0xe0 == 0 {
+        match value < 16 {
             true => Ok(unsafe { core::mem::transmute::<u8, DecompositionTag>(value) }),
-            false => Err(PropertiesError::UnknownPropertyValue),
+            false => Err(PropertiesError::unknown("Decomposition_Type", value)),
         }
     }
 }
@@ -0,0 +1,68 @@
+use super::PropertiesError;
+
+/// East_Asian_Width (ea) - из EastAsianWidth.txt, ширина символа в восточноазиатской типографике
+/// (UAX #11)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth
+{
+    /// N - нейтральные, не используются в восточноазиатской типографике
+    #[default]
+    Neutral,
+    /// A - неоднозначные: широкие в восточноазиатском контексте, иначе - узкие
+    Ambiguous,
+    /// H - полуширинные формы
+    Halfwidth,
+    /// W - широкие
+    Wide,
+    /// F - полноширинные формы
+    Fullwidth,
+    /// Na - узкие
+    Narrow,
+}
+
+impl EastAsianWidth
+{
+    /// обозначение значения в UCD
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::Neutral => "N",
+            Self::Ambiguous => "A",
+            Self::Halfwidth => "H",
+            Self::Wide => "W",
+            Self::Fullwidth => "F",
+            Self::Narrow => "Na",
+        }
+    }
+
+    /// F, W или H - восточноазиатский символ в правилах разрыва строк (UAX #14)
+    pub fn is_east_asian(&self) -> bool
+    {
+        matches!(self, Self::Fullwidth | Self::Wide | Self::Halfwidth)
+    }
+
+    /// занимает две позиции в моноширинном выводе (W, F)
+    pub fn is_wide(&self) -> bool
+    {
+        matches!(self, Self::Wide | Self::Fullwidth)
+    }
+}
+
+impl TryFrom<&str> for EastAsianWidth
+{
+    type Error = PropertiesError;
+
+    #[inline]
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        Ok(match name {
+            "N" | "Neutral" => Self::Neutral,
+            "A" | "Ambiguous" => Self::Ambiguous,
+            "H" | "Halfwidth" => Self::Halfwidth,
+            "W" | "Wide" => Self::Wide,
+            "F" | "Fullwidth" => Self::Fullwidth,
+            "Na" | "Narrow" => Self::Narrow,
+            _ => return Err(PropertiesError::unknown("East_Asian_Width", name)),
+        })
+    }
+}
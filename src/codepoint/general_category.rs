@@ -199,7 +199,7 @@ impl TryFrom<&str> for GeneralCategory
             "Sc" => Self::CurrencySymbol,
             "Sk" => Self::ModifierSymbol,
             "So" => Self::OtherSymbol,
-            _ => return Err(PropertiesError::UnknownPropertyValue),
+            _ => return Err(PropertiesError::unknown("General_Category", abbr)),
         })
     }
 }
@@ -212,7 +212,7 @@ impl TryFrom<u8> for GeneralCategory
     fn try_from(value: u8) -> Result<Self, Self::Error>
     {
         if value == 15 || value == 27 || value > 31 {
-            return Err(PropertiesError::UnknownPropertyValue);
+            return Err(PropertiesError::unknown("General_Category", value));
         }
 
         Ok(unsafe { core::mem::transmute::<u8, GeneralCategory>(value) })
@@ -0,0 +1,88 @@
+use super::PropertiesError;
+
+/// Grapheme_Cluster_Break (GCB) - из GraphemeBreakProperty.txt,
+/// класс кодпоинта для правил сегментации графем (UAX #29)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphemeClusterBreak
+{
+    /// остальные кодпоинты
+    #[default]
+    Other,
+    /// возврат каретки
+    Cr,
+    /// перевод строки
+    Lf,
+    /// управляющие символы и разделители строк / абзацев
+    Control,
+    /// расширяющие символы (Grapheme_Extend, модификаторы эмодзи)
+    Extend,
+    /// ZERO WIDTH JOINER
+    Zwj,
+    /// региональные индикаторы (флаги)
+    RegionalIndicator,
+    /// символы, присоединяющие следующий кодпоинт
+    Prepend,
+    /// комбинируемые метки с пробелом
+    SpacingMark,
+    /// хангыль: начальная согласная
+    L,
+    /// хангыль: гласная
+    V,
+    /// хангыль: конечная согласная
+    T,
+    /// хангыль: слог LV
+    Lv,
+    /// хангыль: слог LVT
+    Lvt,
+}
+
+impl GraphemeClusterBreak
+{
+    /// название значения в UCD
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::Other => "Other",
+            Self::Cr => "CR",
+            Self::Lf => "LF",
+            Self::Control => "Control",
+            Self::Extend => "Extend",
+            Self::Zwj => "ZWJ",
+            Self::RegionalIndicator => "Regional_Indicator",
+            Self::Prepend => "Prepend",
+            Self::SpacingMark => "SpacingMark",
+            Self::L => "L",
+            Self::V => "V",
+            Self::T => "T",
+            Self::Lv => "LV",
+            Self::Lvt => "LVT",
+        }
+    }
+}
+
+impl TryFrom<&str> for GraphemeClusterBreak
+{
+    type Error = PropertiesError;
+
+    #[inline]
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        Ok(match name {
+            "Other" | "XX" => Self::Other,
+            "CR" => Self::Cr,
+            "LF" => Self::Lf,
+            "Control" | "CN" => Self::Control,
+            "Extend" | "EX" => Self::Extend,
+            "ZWJ" => Self::Zwj,
+            "Regional_Indicator" | "RI" => Self::RegionalIndicator,
+            "Prepend" | "PP" => Self::Prepend,
+            "SpacingMark" | "SM" => Self::SpacingMark,
+            "L" => Self::L,
+            "V" => Self::V,
+            "T" => Self::T,
+            "LV" => Self::Lv,
+            "LVT" => Self::Lvt,
+            _ => return Err(PropertiesError::unknown("Grapheme_Cluster_Break", name)),
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use super::PropertiesError;
+
+/// Indic_Conjunct_Break (InCB) - из DerivedCoreProperties.txt,
+/// используется правилом GB9c сегментации графем (UAX #29): конъюнкты индийских письменностей
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndicConjunctBreak
+{
+    /// не участвует в конъюнктах
+    #[default]
+    None,
+    /// вирама, соединяющая согласные
+    Linker,
+    /// согласная
+    Consonant,
+    /// расширяющий символ внутри конъюнкта
+    Extend,
+}
+
+impl IndicConjunctBreak
+{
+    /// название значения в UCD
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::None => "None",
+            Self::Linker => "Linker",
+            Self::Consonant => "Consonant",
+            Self::Extend => "Extend",
+        }
+    }
+}
+
+impl TryFrom<&str> for IndicConjunctBreak
+{
+    type Error = PropertiesError;
+
+    #[inline]
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        Ok(match name {
+            "None" => Self::None,
+            "Linker" => Self::Linker,
+            "Consonant" => Self::Consonant,
+            "Extend" => Self::Extend,
+            _ => return Err(PropertiesError::unknown("Indic_Conjunct_Break", name)),
+        })
+    }
+}
@@ -0,0 +1,253 @@
+use super::{GeneralCategory, PropertiesError};
+
+/// Line_Break (lb) - из LineBreak.txt, класс кодпоинта для правил разрыва строк (UAX #14)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineBreak
+{
+    /// BK - обязательный разрыв после символа
+    MandatoryBreak,
+    /// CR - возврат каретки
+    CarriageReturn,
+    /// LF - перевод строки
+    LineFeed,
+    /// CM - комбинирующие символы и управляющие коды
+    CombiningMark,
+    /// NL - следующая строка (NEL)
+    NextLine,
+    /// SG - суррогаты
+    Surrogate,
+    /// WJ - запрет разрыва до и после символа
+    WordJoiner,
+    /// ZW - пробел нулевой ширины, разрыв после символа
+    ZeroWidthSpace,
+    /// GL - неразрывные символы
+    Glue,
+    /// SP - пробел
+    Space,
+    /// ZWJ - ZERO WIDTH JOINER
+    ZeroWidthJoiner,
+    /// B2 - разрыв до и после, но не между парой символов (длинное тире)
+    BreakBoth,
+    /// BA - разрыв после символа
+    BreakAfter,
+    /// BB - разрыв перед символом
+    BreakBefore,
+    /// HY - дефис-минус
+    Hyphen,
+    /// HH - дефис, не используемый как минус
+    UnambiguousHyphen,
+    /// CB - разрыв зависит от внешних данных (встроенные объекты)
+    ContingentBreak,
+    /// CL - закрывающие знаки пунктуации
+    ClosePunctuation,
+    /// CP - закрывающие скобки
+    CloseParenthesis,
+    /// EX - восклицательный и вопросительный знаки
+    Exclamation,
+    /// IN - многоточия
+    Inseparable,
+    /// NS - символы, с которых не может начинаться строка
+    Nonstarter,
+    /// OP - открывающие знаки пунктуации
+    OpenPunctuation,
+    /// QU - кавычки
+    Quotation,
+    /// IS - разделители внутри чисел
+    InfixNumeric,
+    /// NU - цифры
+    Numeric,
+    /// PO - символы после чисел (проценты, единицы)
+    PostfixNumeric,
+    /// PR - символы перед числами (знаки валют)
+    PrefixNumeric,
+    /// SY - косая черта
+    BreakSymbols,
+    /// AI - неоднозначные (AL или ID в зависимости от контекста)
+    Ambiguous,
+    /// AL - буквы и обычные символы
+    Alphabetic,
+    /// CJ - японские символы, с которых может не начинаться строка (малые каны)
+    ConditionalJapaneseStarter,
+    /// EB - основы модификаторов эмодзи
+    EmojiBase,
+    /// EM - модификаторы эмодзи
+    EmojiModifier,
+    /// H2 - слоги хангыль LV
+    HangulLvSyllable,
+    /// H3 - слоги хангыль LVT
+    HangulLvtSyllable,
+    /// HL - буквы иврита
+    HebrewLetter,
+    /// ID - иероглифы
+    Ideographic,
+    /// JL - начальные согласные чамо
+    HangulLJamo,
+    /// JV - гласные чамо
+    HangulVJamo,
+    /// JT - конечные согласные чамо
+    HangulTJamo,
+    /// RI - региональные индикаторы (флаги)
+    RegionalIndicator,
+    /// SA - письменности Юго-Восточной Азии, разрыв по словарю
+    ComplexContext,
+    /// XX - неизвестные и неназначенные кодпоинты
+    #[default]
+    Unknown,
+    /// AK - акшары
+    Aksara,
+    /// AP - акшары перед основой
+    AksaraPrebase,
+    /// AS - начальные акшары
+    AksaraStart,
+    /// VF - конечная вирама
+    ViramaFinal,
+    /// VI - вирама
+    Virama,
+}
+
+impl LineBreak
+{
+    /// обозначение значения в UCD
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::MandatoryBreak => "BK",
+            Self::CarriageReturn => "CR",
+            Self::LineFeed => "LF",
+            Self::CombiningMark => "CM",
+            Self::NextLine => "NL",
+            Self::Surrogate => "SG",
+            Self::WordJoiner => "WJ",
+            Self::ZeroWidthSpace => "ZW",
+            Self::Glue => "GL",
+            Self::Space => "SP",
+            Self::ZeroWidthJoiner => "ZWJ",
+            Self::BreakBoth => "B2",
+            Self::BreakAfter => "BA",
+            Self::BreakBefore => "BB",
+            Self::Hyphen => "HY",
+            Self::UnambiguousHyphen => "HH",
+            Self::ContingentBreak => "CB",
+            Self::ClosePunctuation => "CL",
+            Self::CloseParenthesis => "CP",
+            Self::Exclamation => "EX",
+            Self::Inseparable => "IN",
+            Self::Nonstarter => "NS",
+            Self::OpenPunctuation => "OP",
+            Self::Quotation => "QU",
+            Self::InfixNumeric => "IS",
+            Self::Numeric => "NU",
+            Self::PostfixNumeric => "PO",
+            Self::PrefixNumeric => "PR",
+            Self::BreakSymbols => "SY",
+            Self::Ambiguous => "AI",
+            Self::Alphabetic => "AL",
+            Self::ConditionalJapaneseStarter => "CJ",
+            Self::EmojiBase => "EB",
+            Self::EmojiModifier => "EM",
+            Self::HangulLvSyllable => "H2",
+            Self::HangulLvtSyllable => "H3",
+            Self::HebrewLetter => "HL",
+            Self::Ideographic => "ID",
+            Self::HangulLJamo => "JL",
+            Self::HangulVJamo => "JV",
+            Self::HangulTJamo => "JT",
+            Self::RegionalIndicator => "RI",
+            Self::ComplexContext => "SA",
+            Self::Unknown => "XX",
+            Self::Aksara => "AK",
+            Self::AksaraPrebase => "AP",
+            Self::AksaraStart => "AS",
+            Self::ViramaFinal => "VF",
+            Self::Virama => "VI",
+        }
+    }
+
+    /// класс после разрешения по правилу LB1 без учёта контекста: AI, SG, XX -> AL, CJ -> NS,
+    /// SA -> CM для комбинирующих символов (Mn, Mc), иначе AL; CB разрешается правилом LB20
+    pub fn resolve(&self, gc: GeneralCategory) -> Self
+    {
+        match self {
+            Self::Ambiguous | Self::Surrogate | Self::Unknown => Self::Alphabetic,
+            Self::ConditionalJapaneseStarter => Self::Nonstarter,
+            Self::ComplexContext => match gc {
+                GeneralCategory::NonspacingMark | GeneralCategory::SpacingMark => {
+                    Self::CombiningMark
+                }
+                _ => Self::Alphabetic,
+            },
+            _ => *self,
+        }
+    }
+
+    /// BK, CR, LF, NL - обязательный разрыв после символа
+    pub fn is_mandatory_break(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::MandatoryBreak | Self::CarriageReturn | Self::LineFeed | Self::NextLine
+        )
+    }
+}
+
+impl TryFrom<&str> for LineBreak
+{
+    type Error = PropertiesError;
+
+    #[inline]
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        Ok(match name {
+            "BK" | "Mandatory_Break" => Self::MandatoryBreak,
+            "CR" | "Carriage_Return" => Self::CarriageReturn,
+            "LF" | "Line_Feed" => Self::LineFeed,
+            "CM" | "Combining_Mark" => Self::CombiningMark,
+            "NL" | "Next_Line" => Self::NextLine,
+            "SG" | "Surrogate" => Self::Surrogate,
+            "WJ" | "Word_Joiner" => Self::WordJoiner,
+            "ZW" | "ZWSpace" => Self::ZeroWidthSpace,
+            "GL" | "Glue" => Self::Glue,
+            "SP" | "Space" => Self::Space,
+            "ZWJ" => Self::ZeroWidthJoiner,
+            "B2" | "Break_Both" => Self::BreakBoth,
+            "BA" | "Break_After" => Self::BreakAfter,
+            "BB" | "Break_Before" => Self::BreakBefore,
+            "HY" | "Hyphen" => Self::Hyphen,
+            "HH" | "Unambiguous_Hyphen" => Self::UnambiguousHyphen,
+            "CB" | "Contingent_Break" => Self::ContingentBreak,
+            "CL" | "Close_Punctuation" => Self::ClosePunctuation,
+            "CP" | "Close_Parenthesis" => Self::CloseParenthesis,
+            "EX" | "Exclamation" => Self::Exclamation,
+            "IN" | "Inseparable" | "Inseperable" => Self::Inseparable,
+            "NS" | "Nonstarter" => Self::Nonstarter,
+            "OP" | "Open_Punctuation" => Self::OpenPunctuation,
+            "QU" | "Quotation" => Self::Quotation,
+            "IS" | "Infix_Numeric" => Self::InfixNumeric,
+            "NU" | "Numeric" => Self::Numeric,
+            "PO" | "Postfix_Numeric" => Self::PostfixNumeric,
+            "PR" | "Prefix_Numeric" => Self::PrefixNumeric,
+            "SY" | "Break_Symbols" => Self::BreakSymbols,
+            "AI" | "Ambiguous" => Self::Ambiguous,
+            "AL" | "Alphabetic" => Self::Alphabetic,
+            "CJ" | "Conditional_Japanese_Starter" => Self::ConditionalJapaneseStarter,
+            "EB" | "E_Base" => Self::EmojiBase,
+            "EM" | "E_Modifier" => Self::EmojiModifier,
+            "H2" => Self::HangulLvSyllable,
+            "H3" => Self::HangulLvtSyllable,
+            "HL" | "Hebrew_Letter" => Self::HebrewLetter,
+            "ID" | "Ideographic" => Self::Ideographic,
+            "JL" => Self::HangulLJamo,
+            "JV" => Self::HangulVJamo,
+            "JT" => Self::HangulTJamo,
+            "RI" | "Regional_Indicator" => Self::RegionalIndicator,
+            "SA" | "Complex_Context" => Self::ComplexContext,
+            "XX" | "Unknown" => Self::Unknown,
+            "AK" | "Aksara" => Self::Aksara,
+            "AP" | "Aksara_Prebase" => Self::AksaraPrebase,
+            "AS" | "Aksara_Start" => Self::AksaraStart,
+            "VF" | "Virama_Final" => Self::ViramaFinal,
+            "VI" | "Virama" => Self::Virama,
+            _ => return Err(PropertiesError::unknown("Line_Break", name)),
+        })
+    }
+}
@@ -1,25 +1,44 @@
 mod bidi_class;
 mod bidi_mirrored;
+mod binary_property;
 mod canonical_combining_class;
 mod decomposition;
+mod east_asian_width;
 mod general_category;
+mod grapheme_cluster_break;
+mod indic_conjunct_break;
+mod line_break;
 mod numeric_type;
+mod script;
+mod sentence_break;
 mod simple_case_mapping;
+mod word_break;
 
 pub use bidi_class::BidiClass;
 pub use bidi_mirrored::BidiMirrored;
+pub use binary_property::BinaryProperty;
 pub use canonical_combining_class::CanonicalCombiningClass;
 pub use decomposition::Decomposition;
 pub use decomposition::DecompositionTag;
+pub use east_asian_width::EastAsianWidth;
 pub use general_category::GeneralCategory;
+pub use grapheme_cluster_break::GraphemeClusterBreak;
+pub use indic_conjunct_break::IndicConjunctBreak;
+pub use line_break::LineBreak;
 pub use numeric_type::NumericType;
+pub use script::Script;
+pub use sentence_break::SentenceBreak;
 pub use simple_case_mapping::SimpleCaseMapping;
+pub use word_break::WordBreak;
 
-use crate::parse::is_composition_exclusion;
 use crate::CodepointsBlock;
+use crate::Dataset;
+use crate::NormalizationProperty;
 
 /// Кодпоинт Unicode
 /// источник - UCD, UnicodeData.txt
+///
+/// свойства из других файлов-источников берутся из набора данных, из которого получен кодпоинт
 #[derive(Debug, Clone)]
 pub struct Codepoint
 {
@@ -47,8 +66,6 @@ pub struct Codepoint
     pub decomposition_tag: Option<DecompositionTag>,
     /// декомпозиция
     pub decomposition: Vec<u32>,
-    // блок, к которому относится кодпоинт (Blocks.txt)
-    pub block: Option<&'static CodepointsBlock>,
 }
 
 impl Codepoint
@@ -81,24 +98,171 @@ impl Codepoint
         self.decomposition_tag.is_some() && !self.decomposition.is_empty()
     }
 
-    /// является ли кодпоинт исключением композиции?
+    /// блок, к которому относится кодпоинт (Blocks.txt набора данных)
+    #[inline]
+    pub fn block_in<'a>(&self, dataset: &'a Dataset) -> Option<&'a CodepointsBlock>
+    {
+        dataset.block_by_code(self.code)
+    }
+
+    /// является ли кодпоинт исключением композиции? (набор данных по умолчанию)
+    #[cfg(feature = "embedded")]
     #[inline]
     pub fn is_composition_exclusion(&self) -> bool
     {
-        is_composition_exclusion(self.code)
+        crate::is_composition_exclusion(self.code)
+    }
+
+    /// является ли кодпоинт исключением композиции?
+    #[inline]
+    pub fn is_composition_exclusion_in(&self, dataset: &Dataset) -> bool
+    {
+        dataset.is_composition_exclusion(self.code)
+    }
+
+    /// является ли кодпоинт полным исключением композиции? (набор данных по умолчанию)
+    #[cfg(feature = "embedded")]
+    #[inline]
+    pub fn is_full_composition_exclusion(&self) -> bool
+    {
+        crate::is_full_composition_exclusion(self.code)
+    }
+
+    /// является ли кодпоинт полным исключением композиции: исключения композиции, синглтоны
+    /// и декомпозиции, начинающиеся с нестартера (Full_Composition_Exclusion)
+    #[inline]
+    pub fn is_full_composition_exclusion_in(&self, dataset: &Dataset) -> bool
+    {
+        dataset.is_full_composition_exclusion(self.code)
+    }
+
+    /// обладает ли кодпоинт бинарным свойством из DerivedNormalizationProps.txt
+    #[inline]
+    pub fn has_normalization_property(
+        &self,
+        dataset: &Dataset,
+        property: NormalizationProperty,
+    ) -> bool
+    {
+        dataset.has_normalization_property(self.code, property)
+    }
+
+    /// письменность (Scripts.txt)
+    #[inline]
+    pub fn script(&self, dataset: &Dataset) -> Script
+    {
+        dataset.script(self.code)
+    }
+
+    /// письменности, в которых используется кодпоинт (ScriptExtensions.txt)
+    #[inline]
+    pub fn script_extensions(&self, dataset: &Dataset) -> Vec<Script>
+    {
+        dataset.script_extensions(self.code)
+    }
+
+    /// обладает ли кодпоинт бинарным свойством (PropList.txt, DerivedCoreProperties.txt,
+    /// emoji-data.txt)
+    #[inline]
+    pub fn has_property(&self, dataset: &Dataset, property: BinaryProperty) -> bool
+    {
+        dataset.has_property(self.code, property)
+    }
+
+    /// значение Indic_Conjunct_Break (DerivedCoreProperties.txt)
+    #[inline]
+    pub fn indic_conjunct_break(&self, dataset: &Dataset) -> IndicConjunctBreak
+    {
+        dataset.indic_conjunct_break(self.code)
+    }
+
+    /// значение Grapheme_Cluster_Break (GraphemeBreakProperty.txt)
+    #[inline]
+    pub fn grapheme_cluster_break(&self, dataset: &Dataset) -> GraphemeClusterBreak
+    {
+        dataset.grapheme_cluster_break(self.code)
+    }
+
+    /// значение Word_Break (WordBreakProperty.txt)
+    #[inline]
+    pub fn word_break(&self, dataset: &Dataset) -> WordBreak
+    {
+        dataset.word_break(self.code)
+    }
+
+    /// значение Sentence_Break (SentenceBreakProperty.txt)
+    #[inline]
+    pub fn sentence_break(&self, dataset: &Dataset) -> SentenceBreak
+    {
+        dataset.sentence_break(self.code)
+    }
+
+    /// значение East_Asian_Width (EastAsianWidth.txt)
+    #[inline]
+    pub fn east_asian_width(&self, dataset: &Dataset) -> EastAsianWidth
+    {
+        dataset.east_asian_width(self.code)
+    }
+
+    /// значение Line_Break (LineBreak.txt), без разрешения по правилу LB1
+    #[inline]
+    pub fn line_break(&self, dataset: &Dataset) -> LineBreak
+    {
+        dataset.line_break(self.code)
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// ошибка получения значения свойства кодпоинта
+#[derive(Debug, Clone, PartialEq)]
 pub enum PropertiesError
 {
-    UnknownPropertyValue,
+    /// неизвестное значение свойства
+    UnknownPropertyValue
+    {
+        /// название свойства (как в UCD, например General_Category)
+        property: &'static str,
+        /// значение, которое не удалось разобрать
+        value: String,
+    },
+    /// некорректное числовое значение свойства
+    InvalidNumber(core::num::ParseIntError),
+}
+
+impl PropertiesError
+{
+    /// неизвестное значение свойства
+    pub fn unknown(property: &'static str, value: impl ToString) -> Self
+    {
+        Self::UnknownPropertyValue {
+            property,
+            value: value.to_string(),
+        }
+    }
 }
 
 impl From<core::num::ParseIntError> for PropertiesError
 {
-    fn from(_: core::num::ParseIntError) -> Self
+    fn from(error: core::num::ParseIntError) -> Self
+    {
+        Self::InvalidNumber(error)
+    }
+}
+
+impl core::fmt::Display for PropertiesError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
     {
-        Self::UnknownPropertyValue
+        match self {
+            Self::UnknownPropertyValue { property, value } => {
+                write!(
+                    f,
+                    "неизвестное значение свойства {}: \"{}\"",
+                    property, value
+                )
+            }
+            Self::InvalidNumber(error) => write!(f, "некорректное числовое значение: {}", error),
+        }
     }
 }
+
+impl std::error::Error for PropertiesError {}
@@ -43,13 +43,27 @@ impl TryFrom<(&str, &str, &str)> for NumericType
             | u8::from(!v.2.is_empty()) << 2;
 
         let value = match mask {
-            0b111 => Self::Decimal(v.0.parse()?),
-            0b110 => Self::Digit(v.1.parse()?),
+            0b111 => Self::Decimal(digit(v.0)?),
+            0b110 => Self::Digit(digit(v.1)?),
             0b100 => Self::Numeric(v.2.to_owned()),
             0b000 => Self::None,
-            _ => return Err(PropertiesError::UnknownPropertyValue),
+            _ => {
+                return Err(PropertiesError::unknown(
+                    "Numeric_Type",
+                    format!("{};{};{}", v.0, v.1, v.2),
+                ))
+            }
         };
 
         Ok(value)
     }
 }
+
+/// значение цифры, от 0 до 9
+fn digit(value: &str) -> Result<u8, PropertiesError>
+{
+    match value.parse() {
+        Ok(digit) if digit <= 9 => Ok(digit),
+        _ => Err(PropertiesError::unknown("Numeric_Value", value)),
+    }
+}
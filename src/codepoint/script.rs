@@ -0,0 +1,258 @@
+use super::PropertiesError;
+
+/// перечисление письменностей: вариант = короткое название, длинное название
+macro_rules! scripts {
+    ($($variant:ident = $abbr:literal, $name:literal;)*) => {
+        /// письменность (Script, sc), ISO 15924
+        /// список ведётся вручную по строкам sc файла PropertyValueAliases.txt (в data/ его нет):
+        /// короткое название (4 буквы) и длинное. при разборе встроенных версий длинные названия
+        /// сверяются со Scripts.txt, короткие - с ScriptExtensions.txt: неизвестное - ошибка.
+        /// Common (Zyyy) - символы, используемые в нескольких письменностях, Inherited (Zinh) -
+        /// наследуют письменность предшествующего символа, Unknown (Zzzz) - неназначенные кодпоинты
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum Script
+        {
+            $($variant,)*
+        }
+
+        impl Script
+        {
+            /// все письменности, в порядке коротких названий
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            /// длинное название, например Latin
+            pub fn as_str(&self) -> &'static str
+            {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            /// короткое название (код ISO 15924), например Latn
+            pub fn abbr(&self) -> &'static str
+            {
+                match self {
+                    $(Self::$variant => $abbr,)*
+                }
+            }
+        }
+    };
+}
+
+scripts! {
+    Adlam = "Adlm", "Adlam";
+    CaucasianAlbanian = "Aghb", "Caucasian_Albanian";
+    Ahom = "Ahom", "Ahom";
+    Arabic = "Arab", "Arabic";
+    ImperialAramaic = "Armi", "Imperial_Aramaic";
+    Armenian = "Armn", "Armenian";
+    Avestan = "Avst", "Avestan";
+    Balinese = "Bali", "Balinese";
+    Bamum = "Bamu", "Bamum";
+    BassaVah = "Bass", "Bassa_Vah";
+    Batak = "Batk", "Batak";
+    Bengali = "Beng", "Bengali";
+    BeriaErfe = "Berf", "Beria_Erfe";
+    Bhaiksuki = "Bhks", "Bhaiksuki";
+    Bopomofo = "Bopo", "Bopomofo";
+    Brahmi = "Brah", "Brahmi";
+    Braille = "Brai", "Braille";
+    Buginese = "Bugi", "Buginese";
+    Buhid = "Buhd", "Buhid";
+    Chakma = "Cakm", "Chakma";
+    CanadianAboriginal = "Cans", "Canadian_Aboriginal";
+    Carian = "Cari", "Carian";
+    Cham = "Cham", "Cham";
+    Cherokee = "Cher", "Cherokee";
+    Chisoi = "Chis", "Chisoi";
+    Chorasmian = "Chrs", "Chorasmian";
+    Coptic = "Copt", "Coptic";
+    CyproMinoan = "Cpmn", "Cypro_Minoan";
+    Cypriot = "Cprt", "Cypriot";
+    Cyrillic = "Cyrl", "Cyrillic";
+    Devanagari = "Deva", "Devanagari";
+    DivesAkuru = "Diak", "Dives_Akuru";
+    Dogra = "Dogr", "Dogra";
+    Deseret = "Dsrt", "Deseret";
+    Duployan = "Dupl", "Duployan";
+    EgyptianHieroglyphs = "Egyp", "Egyptian_Hieroglyphs";
+    Elbasan = "Elba", "Elbasan";
+    Elymaic = "Elym", "Elymaic";
+    Ethiopic = "Ethi", "Ethiopic";
+    Garay = "Gara", "Garay";
+    Georgian = "Geor", "Georgian";
+    Glagolitic = "Glag", "Glagolitic";
+    GunjalaGondi = "Gong", "Gunjala_Gondi";
+    MasaramGondi = "Gonm", "Masaram_Gondi";
+    Gothic = "Goth", "Gothic";
+    Grantha = "Gran", "Grantha";
+    Greek = "Grek", "Greek";
+    Gujarati = "Gujr", "Gujarati";
+    GurungKhema = "Gukh", "Gurung_Khema";
+    Gurmukhi = "Guru", "Gurmukhi";
+    Hangul = "Hang", "Hangul";
+    Han = "Hani", "Han";
+    Hanunoo = "Hano", "Hanunoo";
+    Hatran = "Hatr", "Hatran";
+    Hebrew = "Hebr", "Hebrew";
+    Hiragana = "Hira", "Hiragana";
+    AnatolianHieroglyphs = "Hluw", "Anatolian_Hieroglyphs";
+    PahawhHmong = "Hmng", "Pahawh_Hmong";
+    NyiakengPuachueHmong = "Hmnp", "Nyiakeng_Puachue_Hmong";
+    KatakanaOrHiragana = "Hrkt", "Katakana_Or_Hiragana";
+    OldHungarian = "Hung", "Old_Hungarian";
+    OldItalic = "Ital", "Old_Italic";
+    Javanese = "Java", "Javanese";
+    KayahLi = "Kali", "Kayah_Li";
+    Katakana = "Kana", "Katakana";
+    Kawi = "Kawi", "Kawi";
+    Kharoshthi = "Khar", "Kharoshthi";
+    Khmer = "Khmr", "Khmer";
+    Khojki = "Khoj", "Khojki";
+    KhitanSmallScript = "Kits", "Khitan_Small_Script";
+    Kannada = "Knda", "Kannada";
+    KiratRai = "Krai", "Kirat_Rai";
+    Kaithi = "Kthi", "Kaithi";
+    TaiTham = "Lana", "Tai_Tham";
+    Lao = "Laoo", "Lao";
+    Latin = "Latn", "Latin";
+    Lepcha = "Lepc", "Lepcha";
+    Limbu = "Limb", "Limbu";
+    LinearA = "Lina", "Linear_A";
+    LinearB = "Linb", "Linear_B";
+    Lisu = "Lisu", "Lisu";
+    Lycian = "Lyci", "Lycian";
+    Lydian = "Lydi", "Lydian";
+    Mahajani = "Mahj", "Mahajani";
+    Makasar = "Maka", "Makasar";
+    Mandaic = "Mand", "Mandaic";
+    Manichaean = "Mani", "Manichaean";
+    Marchen = "Marc", "Marchen";
+    Medefaidrin = "Medf", "Medefaidrin";
+    MendeKikakui = "Mend", "Mende_Kikakui";
+    MeroiticCursive = "Merc", "Meroitic_Cursive";
+    MeroiticHieroglyphs = "Mero", "Meroitic_Hieroglyphs";
+    Malayalam = "Mlym", "Malayalam";
+    Modi = "Modi", "Modi";
+    Mongolian = "Mong", "Mongolian";
+    Mro = "Mroo", "Mro";
+    MeeteiMayek = "Mtei", "Meetei_Mayek";
+    Multani = "Mult", "Multani";
+    Myanmar = "Mymr", "Myanmar";
+    NagMundari = "Nagm", "Nag_Mundari";
+    Nandinagari = "Nand", "Nandinagari";
+    OldNorthArabian = "Narb", "Old_North_Arabian";
+    Nabataean = "Nbat", "Nabataean";
+    Newa = "Newa", "Newa";
+    Nko = "Nkoo", "Nko";
+    Nushu = "Nshu", "Nushu";
+    Ogham = "Ogam", "Ogham";
+    OlChiki = "Olck", "Ol_Chiki";
+    OlOnal = "Onao", "Ol_Onal";
+    OldTurkic = "Orkh", "Old_Turkic";
+    Oriya = "Orya", "Oriya";
+    Osage = "Osge", "Osage";
+    Osmanya = "Osma", "Osmanya";
+    OldUyghur = "Ougr", "Old_Uyghur";
+    Palmyrene = "Palm", "Palmyrene";
+    PauCinHau = "Pauc", "Pau_Cin_Hau";
+    OldPermic = "Perm", "Old_Permic";
+    PhagsPa = "Phag", "Phags_Pa";
+    InscriptionalPahlavi = "Phli", "Inscriptional_Pahlavi";
+    PsalterPahlavi = "Phlp", "Psalter_Pahlavi";
+    Phoenician = "Phnx", "Phoenician";
+    Miao = "Plrd", "Miao";
+    InscriptionalParthian = "Prti", "Inscriptional_Parthian";
+    Rejang = "Rjng", "Rejang";
+    HanifiRohingya = "Rohg", "Hanifi_Rohingya";
+    Runic = "Runr", "Runic";
+    Samaritan = "Samr", "Samaritan";
+    OldSouthArabian = "Sarb", "Old_South_Arabian";
+    Saurashtra = "Saur", "Saurashtra";
+    SignWriting = "Sgnw", "SignWriting";
+    Shavian = "Shaw", "Shavian";
+    Sharada = "Shrd", "Sharada";
+    Siddham = "Sidd", "Siddham";
+    Sidetic = "Sidt", "Sidetic";
+    Khudawadi = "Sind", "Khudawadi";
+    Sinhala = "Sinh", "Sinhala";
+    Sogdian = "Sogd", "Sogdian";
+    OldSogdian = "Sogo", "Old_Sogdian";
+    SoraSompeng = "Sora", "Sora_Sompeng";
+    Soyombo = "Soyo", "Soyombo";
+    Sundanese = "Sund", "Sundanese";
+    Sunuwar = "Sunu", "Sunuwar";
+    SylotiNagri = "Sylo", "Syloti_Nagri";
+    Syriac = "Syrc", "Syriac";
+    Tagbanwa = "Tagb", "Tagbanwa";
+    Takri = "Takr", "Takri";
+    TaiLe = "Tale", "Tai_Le";
+    NewTaiLue = "Talu", "New_Tai_Lue";
+    Tamil = "Taml", "Tamil";
+    Tangut = "Tang", "Tangut";
+    TaiViet = "Tavt", "Tai_Viet";
+    TaiYo = "Tayo", "Tai_Yo";
+    Telugu = "Telu", "Telugu";
+    Tifinagh = "Tfng", "Tifinagh";
+    Tagalog = "Tglg", "Tagalog";
+    Thaana = "Thaa", "Thaana";
+    Thai = "Thai", "Thai";
+    Tibetan = "Tibt", "Tibetan";
+    Tirhuta = "Tirh", "Tirhuta";
+    Tangsa = "Tnsa", "Tangsa";
+    Todhri = "Todr", "Todhri";
+    TolongSiki = "Tols", "Tolong_Siki";
+    Toto = "Toto", "Toto";
+    TuluTigalari = "Tutg", "Tulu_Tigalari";
+    Ugaritic = "Ugar", "Ugaritic";
+    Vai = "Vaii", "Vai";
+    Vithkuqi = "Vith", "Vithkuqi";
+    WarangCiti = "Wara", "Warang_Citi";
+    Wancho = "Wcho", "Wancho";
+    OldPersian = "Xpeo", "Old_Persian";
+    Cuneiform = "Xsux", "Cuneiform";
+    Yezidi = "Yezi", "Yezidi";
+    Yi = "Yiii", "Yi";
+    ZanabazarSquare = "Zanb", "Zanabazar_Square";
+    Inherited = "Zinh", "Inherited";
+    Common = "Zyyy", "Common";
+    Unknown = "Zzzz", "Unknown";
+}
+
+impl Script
+{
+    /// письменность не определяется символом: Common, Inherited или Unknown
+    pub fn is_special(&self) -> bool
+    {
+        matches!(self, Self::Common | Self::Inherited | Self::Unknown)
+    }
+}
+
+impl TryFrom<&str> for Script
+{
+    type Error = PropertiesError;
+
+    /// по короткому или длинному названию, а также устаревшим псевдонимам Qaac / Qaai
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        match name {
+            "Qaac" => return Ok(Self::Coptic),
+            "Qaai" => return Ok(Self::Inherited),
+            _ => (),
+        }
+
+        Self::ALL
+            .iter()
+            .find(|script| script.abbr() == name || script.as_str() == name)
+            .copied()
+            .ok_or_else(|| PropertiesError::unknown("Script", name))
+    }
+}
+
+impl core::fmt::Display for Script
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.write_str(self.as_str())
+    }
+}
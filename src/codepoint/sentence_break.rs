@@ -0,0 +1,104 @@
+use super::PropertiesError;
+
+/// Sentence_Break (SB) - из SentenceBreakProperty.txt, класс кодпоинта для правил границ
+/// предложений (UAX #29)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SentenceBreak
+{
+    /// остальные кодпоинты
+    #[default]
+    Other,
+    /// возврат каретки
+    Cr,
+    /// перевод строки
+    Lf,
+    /// расширяющие символы
+    Extend,
+    /// разделители строк и абзацев
+    Sep,
+    /// символы форматирования
+    Format,
+    /// пробелы
+    Sp,
+    /// строчные буквы
+    Lower,
+    /// прописные и заглавные буквы
+    Upper,
+    /// прочие буквы
+    OLetter,
+    /// цифры
+    Numeric,
+    /// точка - конец предложения или сокращение
+    ATerm,
+    /// знаки, продолжающие предложение (запятая, двоеточие, тире)
+    SContinue,
+    /// знаки конца предложения
+    STerm,
+    /// закрывающие скобки и кавычки
+    Close,
+}
+
+impl SentenceBreak
+{
+    /// название значения в UCD
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::Other => "Other",
+            Self::Cr => "CR",
+            Self::Lf => "LF",
+            Self::Extend => "Extend",
+            Self::Sep => "Sep",
+            Self::Format => "Format",
+            Self::Sp => "Sp",
+            Self::Lower => "Lower",
+            Self::Upper => "Upper",
+            Self::OLetter => "OLetter",
+            Self::Numeric => "Numeric",
+            Self::ATerm => "ATerm",
+            Self::SContinue => "SContinue",
+            Self::STerm => "STerm",
+            Self::Close => "Close",
+        }
+    }
+
+    /// разделитель абзацев (ParaSep): Sep, CR, LF
+    pub fn is_paragraph_separator(&self) -> bool
+    {
+        matches!(self, Self::Sep | Self::Cr | Self::Lf)
+    }
+
+    /// знак конца предложения (SATerm): STerm, ATerm
+    pub fn is_terminator(&self) -> bool
+    {
+        matches!(self, Self::STerm | Self::ATerm)
+    }
+}
+
+impl TryFrom<&str> for SentenceBreak
+{
+    type Error = PropertiesError;
+
+    #[inline]
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        Ok(match name {
+            "Other" | "XX" => Self::Other,
+            "CR" => Self::Cr,
+            "LF" => Self::Lf,
+            "Extend" | "EX" => Self::Extend,
+            "Sep" | "SE" => Self::Sep,
+            "Format" | "FO" => Self::Format,
+            "Sp" | "SP" => Self::Sp,
+            "Lower" | "LO" => Self::Lower,
+            "Upper" | "UP" => Self::Upper,
+            "OLetter" | "LE" => Self::OLetter,
+            "Numeric" | "NU" => Self::Numeric,
+            "ATerm" | "AT" => Self::ATerm,
+            "SContinue" | "SC" => Self::SContinue,
+            "STerm" | "ST" => Self::STerm,
+            "Close" | "CL" => Self::Close,
+            _ => return Err(PropertiesError::unknown("Sentence_Break", name)),
+        })
+    }
+}
@@ -27,7 +27,7 @@ impl TryFrom<&str> for SimpleCaseMapping
             true => Self::None,
             false => match u32::from_str_radix(value, 16) {
                 Ok(value) => Self::Some(value),
-                Err(_) => return Err(PropertiesError::UnknownPropertyValue),
+                Err(_) => return Err(PropertiesError::unknown("Simple_Case_Mapping", value)),
             },
         })
     }
@@ -0,0 +1,113 @@
+use super::PropertiesError;
+
+/// Word_Break (WB) - из WordBreakProperty.txt, класс кодпоинта для правил границ слов (UAX #29)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordBreak
+{
+    /// остальные кодпоинты
+    #[default]
+    Other,
+    /// возврат каретки
+    Cr,
+    /// перевод строки
+    Lf,
+    /// прочие разделители строк
+    Newline,
+    /// расширяющие символы
+    Extend,
+    /// ZERO WIDTH JOINER
+    Zwj,
+    /// региональные индикаторы (флаги)
+    RegionalIndicator,
+    /// символы форматирования
+    Format,
+    /// катакана
+    Katakana,
+    /// буквы иврита
+    HebrewLetter,
+    /// буквы алфавитных письменностей
+    ALetter,
+    /// апостроф
+    SingleQuote,
+    /// кавычка
+    DoubleQuote,
+    /// точка и т.п. - внутри слов и чисел
+    MidNumLet,
+    /// двоеточие и т.п. - внутри слов
+    MidLetter,
+    /// запятая и т.п. - внутри чисел
+    MidNum,
+    /// цифры
+    Numeric,
+    /// соединители (знак подчёркивания и т.п.)
+    ExtendNumLet,
+    /// пробелы, разделяющие слова
+    WSegSpace,
+}
+
+impl WordBreak
+{
+    /// название значения в UCD
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::Other => "Other",
+            Self::Cr => "CR",
+            Self::Lf => "LF",
+            Self::Newline => "Newline",
+            Self::Extend => "Extend",
+            Self::Zwj => "ZWJ",
+            Self::RegionalIndicator => "Regional_Indicator",
+            Self::Format => "Format",
+            Self::Katakana => "Katakana",
+            Self::HebrewLetter => "Hebrew_Letter",
+            Self::ALetter => "ALetter",
+            Self::SingleQuote => "Single_Quote",
+            Self::DoubleQuote => "Double_Quote",
+            Self::MidNumLet => "MidNumLet",
+            Self::MidLetter => "MidLetter",
+            Self::MidNum => "MidNum",
+            Self::Numeric => "Numeric",
+            Self::ExtendNumLet => "ExtendNumLet",
+            Self::WSegSpace => "WSegSpace",
+        }
+    }
+
+    /// ALetter или Hebrew_Letter (AHLetter)
+    pub fn is_letter(&self) -> bool
+    {
+        matches!(self, Self::ALetter | Self::HebrewLetter)
+    }
+}
+
+impl TryFrom<&str> for WordBreak
+{
+    type Error = PropertiesError;
+
+    #[inline]
+    fn try_from(name: &str) -> Result<Self, Self::Error>
+    {
+        Ok(match name {
+            "Other" | "XX" => Self::Other,
+            "CR" => Self::Cr,
+            "LF" => Self::Lf,
+            "Newline" | "NL" => Self::Newline,
+            "Extend" => Self::Extend,
+            "ZWJ" => Self::Zwj,
+            "Regional_Indicator" | "RI" => Self::RegionalIndicator,
+            "Format" | "FO" => Self::Format,
+            "Katakana" | "KA" => Self::Katakana,
+            "Hebrew_Letter" | "HL" => Self::HebrewLetter,
+            "ALetter" | "LE" => Self::ALetter,
+            "Single_Quote" | "SQ" => Self::SingleQuote,
+            "Double_Quote" | "DQ" => Self::DoubleQuote,
+            "MidNumLet" | "MB" => Self::MidNumLet,
+            "MidLetter" | "ML" => Self::MidLetter,
+            "MidNum" | "MN" => Self::MidNum,
+            "Numeric" | "NU" => Self::Numeric,
+            "ExtendNumLet" | "EX" => Self::ExtendNumLet,
+            "WSegSpace" => Self::WSegSpace,
+            _ => return Err(PropertiesError::unknown("Word_Break", name)),
+        })
+    }
+}
@@ -0,0 +1,358 @@
+use std::fmt::Write;
+
+use crate::normalization::decompose;
+use crate::{
+    CollationMatch, CollationTable, CollationTest, Collator, Dataset, ParseError, SourceFile,
+    Strength, VariableWeighting,
+};
+
+// проверка соответствия CollationTest_*.txt, UTS #10, 11.2:
+//  * каждая строка теста не меньше предыдущей, при равных ключах - порядок кодпоинтов NFD
+//  * если в строке указаны веса (полные версии файлов) - вычисленный ключ совпадает с ожидаемым
+
+/// набор тестов сопоставления
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollationTestSuite
+{
+    /// DUCET, NON_IGNORABLE
+    DucetNonIgnorable,
+    /// DUCET, SHIFTED
+    DucetShifted,
+    /// CLDR root, NON_IGNORABLE
+    CldrNonIgnorable,
+    /// CLDR root, SHIFTED
+    CldrShifted,
+}
+
+/// результат проверки набора тестов
+#[derive(Debug, Clone)]
+pub struct CollationTestReport
+{
+    /// набор тестов
+    pub suite: CollationTestSuite,
+    /// количество успешно пройденных строк
+    pub passed: usize,
+    /// ошибки
+    pub failures: Vec<CollationTestFailure>,
+}
+
+/// строка теста, не прошедшая проверку
+#[derive(Debug, Clone)]
+pub struct CollationTestFailure
+{
+    /// номер строки в файле теста
+    pub line: usize,
+    /// последовательность кодов
+    pub codes: Vec<u32>,
+    /// описание
+    pub description: String,
+    /// что именно не совпало
+    pub kind: CollationTestFailureKind,
+    /// найденные в дереве весов последовательности кодпоинтов
+    pub trie_path: Vec<CollationMatch>,
+}
+
+/// причина ошибки теста сопоставления
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollationTestFailureKind
+{
+    /// строка оказалась меньше предыдущей
+    Order
+    {
+        previous_line: usize,
+        previous_codes: Vec<u32>,
+        previous_key: Vec<u16>,
+        key: Vec<u16>,
+    },
+    /// вычисленный ключ сортировки не совпадает с ожидаемым
+    Key
+    {
+        expected: Vec<u16>,
+        actual: Vec<u16>,
+    },
+}
+
+impl CollationTestSuite
+{
+    /// все наборы тестов
+    pub const ALL: [Self; 4] = [
+        Self::DucetNonIgnorable,
+        Self::DucetShifted,
+        Self::CldrNonIgnorable,
+        Self::CldrShifted,
+    ];
+
+    /// файл-источник
+    pub fn source_file(&self) -> SourceFile
+    {
+        match self {
+            Self::DucetNonIgnorable => SourceFile::CollationTestDucetNonIgnorable,
+            Self::DucetShifted => SourceFile::CollationTestDucetShifted,
+            Self::CldrNonIgnorable => SourceFile::CollationTestCldrNonIgnorable,
+            Self::CldrShifted => SourceFile::CollationTestCldrShifted,
+        }
+    }
+
+    /// тесты набора данных
+    pub fn tests<'a>(&self, dataset: &'a Dataset) -> &'a Vec<CollationTest>
+    {
+        match self.try_tests(dataset) {
+            Ok(tests) => tests,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// тесты набора данных; ошибка - если файла тестов нет или его не удалось разобрать
+    pub fn try_tests<'a>(&self, dataset: &'a Dataset) -> Result<&'a Vec<CollationTest>, ParseError>
+    {
+        match self {
+            Self::DucetNonIgnorable => dataset.try_collation_test_ducet_non_ignorable(),
+            Self::DucetShifted => dataset.try_collation_test_ducet_shifted(),
+            Self::CldrNonIgnorable => dataset.try_collation_test_cldr_non_ignorable(),
+            Self::CldrShifted => dataset.try_collation_test_cldr_shifted(),
+        }
+    }
+
+    /// параметры сопоставления, которым соответствует набор тестов
+    pub fn collator<'a>(&self, dataset: &'a Dataset) -> Collator<'a>
+    {
+        let table = match self {
+            Self::DucetNonIgnorable | Self::DucetShifted => CollationTable::Ducet,
+            Self::CldrNonIgnorable | Self::CldrShifted => CollationTable::CldrRoot,
+        };
+
+        let collator = Collator::new(dataset, table);
+
+        match self {
+            Self::DucetNonIgnorable | Self::CldrNonIgnorable => collator,
+            Self::DucetShifted | Self::CldrShifted => collator
+                .strength(Strength::Quaternary)
+                .variable_weighting(VariableWeighting::Shifted),
+        }
+    }
+
+    /// проверить набор тестов, паникует - если файла тестов нет в наборе данных
+    pub fn run(&self, dataset: &Dataset) -> CollationTestReport
+    {
+        match self.try_run(dataset) {
+            Ok(report) => report,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// проверить набор тестов; ошибка - если файла тестов нет или его не удалось разобрать
+    pub fn try_run(&self, dataset: &Dataset) -> Result<CollationTestReport, ParseError>
+    {
+        let collator = self.collator(dataset);
+
+        let mut report = CollationTestReport {
+            suite: *self,
+            passed: 0,
+            failures: vec![],
+        };
+
+        let mut previous: Option<(&CollationTest, Vec<u16>, Vec<u32>)> = None;
+
+        for test in self.try_tests(dataset)? {
+            let key = collator.sort_key_codes(&test.codes);
+            let nfd = decompose(dataset, &test.codes, true);
+            let mut kinds = vec![];
+
+            if let Some(expected) = expected_key(test, &collator) {
+                if expected != key {
+                    kinds.push(CollationTestFailureKind::Key {
+                        expected,
+                        actual: key.clone(),
+                    });
+                }
+            }
+
+            if let Some((previous_test, previous_key, previous_nfd)) = &previous {
+                if (previous_key, previous_nfd) > (&key, &nfd) {
+                    kinds.push(CollationTestFailureKind::Order {
+                        previous_line: previous_test.line,
+                        previous_codes: previous_test.codes.clone(),
+                        previous_key: previous_key.clone(),
+                        key: key.clone(),
+                    });
+                }
+            }
+
+            if kinds.is_empty() {
+                report.passed += 1;
+            }
+
+            for kind in kinds {
+                report.failures.push(CollationTestFailure {
+                    line: test.line,
+                    codes: test.codes.clone(),
+                    description: test.description.clone(),
+                    kind,
+                    trie_path: collator.lookup_codes(&test.codes),
+                });
+            }
+
+            previous = Some((test, key, nfd));
+        }
+
+        Ok(report)
+    }
+}
+
+impl core::fmt::Display for CollationTestSuite
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.write_str(self.source_file().file_names()[0])
+    }
+}
+
+impl CollationTestReport
+{
+    /// все строки прошли проверку
+    pub fn is_ok(&self) -> bool
+    {
+        self.failures.is_empty()
+    }
+}
+
+impl core::fmt::Display for CollationTestReport
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        writeln!(
+            f,
+            "{}: пройдено {}, ошибок {}",
+            self.suite,
+            self.passed,
+            self.failures.len()
+        )?;
+
+        for failure in &self.failures {
+            write!(f, "{}", failure)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for CollationTestFailure
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(f, "{}: {}", self.line, format_codes(&self.codes))?;
+
+        if !self.description.is_empty() {
+            write!(f, " # {}", self.description)?;
+        }
+
+        writeln!(f)?;
+
+        match &self.kind {
+            CollationTestFailureKind::Order {
+                previous_line,
+                previous_codes,
+                previous_key,
+                key,
+            } => {
+                writeln!(
+                    f,
+                    "  порядок: меньше строки {} ({})",
+                    previous_line,
+                    format_codes(previous_codes)
+                )?;
+                writeln!(f, "  - {}", format_key(previous_key))?;
+                writeln!(f, "  + {}", format_key(key))?;
+            }
+            CollationTestFailureKind::Key { expected, actual } => {
+                writeln!(f, "  ключ:")?;
+                writeln!(f, "  - {}", format_key(expected))?;
+                writeln!(f, "  + {}", format_key(actual))?;
+            }
+        }
+
+        writeln!(f, "  путь: {}", format_trie_path(&self.trie_path))
+    }
+}
+
+/// ожидаемый ключ сортировки - если в строке теста указаны веса
+fn expected_key(test: &CollationTest, collator: &Collator) -> Option<Vec<u16>>
+{
+    let levels = [&test.l1, &test.l2, &test.l3, &test.l4];
+
+    if levels.iter().all(|level| level.is_empty()) {
+        return None;
+    }
+
+    let mut key = vec![];
+
+    for (i, level) in levels.iter().take(collator.strength as usize).enumerate() {
+        if i > 0 {
+            key.push(0);
+        }
+
+        key.extend(level.iter());
+    }
+
+    Some(key)
+}
+
+/// кодпоинты в виде XXXX YYYY
+fn format_codes(codes: &[u32]) -> String
+{
+    codes
+        .iter()
+        .map(|code| format!("{:04X}", code))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// ключ сортировки в виде [L1 | L2 | L3 | L4]
+fn format_key(key: &[u16]) -> String
+{
+    let levels = key
+        .split(|&weight| weight == 0)
+        .map(|level| {
+            level
+                .iter()
+                .map(|weight| format!("{:04X}", weight))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", levels.join(" | "))
+}
+
+/// последовательности кодпоинтов, найденные в дереве весов, и их элементы сопоставления
+fn format_trie_path(path: &[CollationMatch]) -> String
+{
+    let mut result = String::new();
+
+    for (i, found) in path.iter().enumerate() {
+        if i > 0 {
+            result.push_str(" / ");
+        }
+
+        result.push_str(&format_codes(&found.codes));
+
+        if found.is_implicit {
+            result.push_str(" (implicit)");
+        }
+
+        result.push(' ');
+
+        for weights in &found.weights {
+            let _ = write!(
+                result,
+                "[{}{:04X}.{:04X}.{:04X}]",
+                if weights.is_variable { '*' } else { '.' },
+                weights.l1,
+                weights.l2,
+                weights.l3
+            );
+        }
+    }
+
+    result
+}
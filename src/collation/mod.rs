@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::normalization::decompose;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, TrieNode, Weights};
+
+mod conformance;
+
+pub use conformance::*;
+
+// сопоставление строк по UCA, UTS #10: https://www.unicode.org/reports/tr10/
+//  * S1: NFD
+//  * S2: поиск элементов сопоставления - наиболее длинное совпадение в дереве весов, включая
+//    несмежные сокращения (S2.1.1 - S2.1.3), вычисляемые веса для отсутствующих в таблице кодпоинтов
+//  * S3, S4: ключ сортировки и его сравнение
+
+/// таблица весов, по которой выполняется сопоставление
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollationTable
+{
+    /// DUCET (allkeys.txt)
+    #[default]
+    Ducet,
+    /// корневая локаль CLDR (allkeys_CLDR.txt)
+    CldrRoot,
+}
+
+/// количество уровней сравнения
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Strength
+{
+    /// только базовые символы
+    Primary = 1,
+    /// + диакритические знаки
+    Secondary = 2,
+    /// + регистр и варианты написания
+    #[default]
+    Tertiary = 3,
+    /// + четвёртый уровень, смотри VariableWeighting
+    Quaternary = 4,
+}
+
+/// обработка переменных элементов сопоставления (пробелы, пунктуация, символы), UTS #10, 4
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariableWeighting
+{
+    /// веса не меняются, на четвёртом уровне - FFFF для каждого не полностью игнорируемого элемента
+    #[default]
+    NonIgnorable,
+    /// праймари переменных элементов переносится на четвёртый уровень, остальные получают FFFF
+    Shifted,
+    /// переменные элементы и следующие за ними игнорируемые не учитываются, четвёртого уровня нет
+    Blanked,
+    /// как Shifted, но завершающие FFFF четвёртого уровня отбрасываются
+    ShiftTrimmed,
+}
+
+/// последовательность кодпоинтов, найденная при поиске элементов сопоставления
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollationMatch
+{
+    /// кодпоинты - запись таблицы, в том числе несмежное сокращение, или один кодпоинт
+    pub codes: Vec<u32>,
+    /// элементы сопоставления
+    pub weights: Vec<Weights>,
+    /// кодпоинт отсутствует в таблице, веса вычислены
+    pub is_implicit: bool,
+}
+
+/// сопоставление строк по UCA
+#[derive(Debug, Clone, Copy)]
+pub struct Collator<'a>
+{
+    dataset: &'a Dataset,
+    table: CollationTable,
+    strength: Strength,
+    variable_weighting: VariableWeighting,
+}
+
+#[cfg(feature = "embedded")]
+impl Default for Collator<'_>
+{
+    fn default() -> Self
+    {
+        Self::new(
+            UnicodeVersion::default().dataset(),
+            CollationTable::default(),
+        )
+    }
+}
+
+impl<'a> Collator<'a>
+{
+    /// сопоставление по таблице набора данных, до третьего уровня
+    pub fn new(dataset: &'a Dataset, table: CollationTable) -> Self
+    {
+        Self {
+            dataset,
+            table,
+            strength: Strength::default(),
+            variable_weighting: VariableWeighting::default(),
+        }
+    }
+
+    /// задать количество уровней сравнения
+    pub fn strength(mut self, strength: Strength) -> Self
+    {
+        self.strength = strength;
+        self
+    }
+
+    /// задать обработку переменных элементов сопоставления
+    pub fn variable_weighting(mut self, variable_weighting: VariableWeighting) -> Self
+    {
+        self.variable_weighting = variable_weighting;
+        self
+    }
+
+    /// таблица весов
+    pub fn table(&self) -> CollationTable
+    {
+        self.table
+    }
+
+    /// сравнить строки
+    pub fn compare(&self, a: &str, b: &str) -> Ordering
+    {
+        self.compare_codes(&codes(a), &codes(b))
+    }
+
+    /// сравнить последовательности кодпоинтов
+    pub fn compare_codes(&self, a: &[u32], b: &[u32]) -> Ordering
+    {
+        self.sort_key_codes(a).cmp(&self.sort_key_codes(b))
+    }
+
+    /// элементы сопоставления строки
+    pub fn collation_elements(&self, text: &str) -> Vec<Weights>
+    {
+        self.collation_elements_codes(&codes(text))
+    }
+
+    /// элементы сопоставления последовательности кодпоинтов
+    pub fn collation_elements_codes(&self, codes: &[u32]) -> Vec<Weights>
+    {
+        self.lookup_codes(codes)
+            .into_iter()
+            .flat_map(|m| m.weights)
+            .collect()
+    }
+
+    /// найденные в дереве весов последовательности кодпоинтов (после NFD) и их элементы сопоставления
+    pub fn lookup_codes(&self, codes: &[u32]) -> Vec<CollationMatch>
+    {
+        let mut codes = decompose(self.dataset, codes, true);
+        let mut result = vec![];
+        let mut i = 0;
+
+        while i < codes.len() {
+            let implicit = |code| CollationMatch {
+                codes: vec![code],
+                weights: self.dataset.implicit_collation_elements(code).to_vec(),
+                is_implicit: true,
+            };
+
+            let mut node = match self.trie().get(&codes[i]) {
+                Some(node) => node,
+                None => {
+                    result.push(implicit(codes[i]));
+                    i += 1;
+                    continue;
+                }
+            };
+
+            // S2.1: наиболее длинное совпадение, промежуточные узлы совпадением не являются
+            let mut end = i + 1;
+            let mut next = (node, end);
+
+            while let Some(child) = child(next.0, codes.get(next.1)) {
+                next = (child, next.1 + 1);
+
+                if child.is_entry {
+                    (node, end) = next;
+                }
+            }
+
+            if !node.is_entry {
+                result.push(implicit(codes[i]));
+                i += 1;
+                continue;
+            }
+
+            let mut matched = codes[i .. end].to_vec();
+
+            // S2.1.1 - S2.1.3: несмежные сокращения - незаблокированные нестартеры, следующие за S
+            let mut skipped_ccc = 0;
+            let mut j = end;
+
+            while node.children.is_some() && j < codes.len() {
+                let ccc = self.ccc(codes[j]);
+
+                if ccc == 0 {
+                    break;
+                }
+
+                if j == end || skipped_ccc < ccc {
+                    if let Some(child) = child(node, Some(&codes[j])).filter(|c| c.is_entry) {
+                        node = child;
+                        matched.push(codes.remove(j));
+                        continue;
+                    }
+                }
+
+                skipped_ccc = ccc;
+                j += 1;
+            }
+
+            result.push(CollationMatch {
+                codes: matched,
+                weights: node.weights.clone(),
+                is_implicit: false,
+            });
+            i = end;
+        }
+
+        result
+    }
+
+    /// ключ сортировки строки
+    pub fn sort_key(&self, text: &str) -> Vec<u16>
+    {
+        self.sort_key_codes(&codes(text))
+    }
+
+    /// ключ сортировки последовательности кодпоинтов: ненулевые веса уровней, разделённые 0000,
+    /// в том же виде, что и поля l1 - l4 CollationTest
+    pub fn sort_key_codes(&self, codes: &[u32]) -> Vec<u16>
+    {
+        let elements = self.weighted(&self.collation_elements_codes(codes));
+
+        let mut levels: [Vec<u16>; 4] = Default::default();
+
+        for weights in elements.iter() {
+            for (level, &weight) in levels.iter_mut().zip(weights) {
+                if weight != 0 {
+                    level.push(weight);
+                }
+            }
+        }
+
+        match self.variable_weighting {
+            VariableWeighting::Blanked => levels[3].clear(),
+            VariableWeighting::ShiftTrimmed => {
+                while levels[3].last() == Some(&0xFFFF) {
+                    levels[3].pop();
+                }
+            }
+            _ => (),
+        }
+
+        let mut key = vec![];
+
+        for (i, level) in levels.iter().take(self.strength as usize).enumerate() {
+            if i > 0 {
+                key.push(0);
+            }
+
+            key.extend(level);
+        }
+
+        key
+    }
+
+    /// веса четырёх уровней с учётом обработки переменных элементов сопоставления, UTS #10, таблица 11
+    fn weighted(&self, elements: &[Weights]) -> Vec<[u16; 4]>
+    {
+        let mut after_variable = false;
+
+        elements
+            .iter()
+            .map(|w| {
+                let ignorable = w.l1 == 0 && w.l2 == 0 && w.l3 == 0;
+
+                if self.variable_weighting == VariableWeighting::NonIgnorable || ignorable {
+                    return [w.l1, w.l2, w.l3, if ignorable { 0 } else { 0xFFFF }];
+                }
+
+                let shifted = self.variable_weighting != VariableWeighting::Blanked;
+
+                if w.is_variable && w.l1 != 0 {
+                    after_variable = true;
+                    return [0, 0, 0, if shifted { w.l1 } else { 0 }];
+                }
+
+                if w.l1 == 0 && after_variable {
+                    return [0, 0, 0, 0];
+                }
+
+                after_variable = false;
+
+                [w.l1, w.l2, w.l3, 0xFFFF]
+            })
+            .collect()
+    }
+
+    /// дерево весов выбранной таблицы
+    fn trie(&self) -> &'a HashMap<u32, TrieNode>
+    {
+        match self.table {
+            CollationTable::Ducet => self.dataset.ducet_trie(),
+            CollationTable::CldrRoot => self.dataset.cldr_und_trie(),
+        }
+    }
+
+    /// класс канонического комбинирования кодпоинта
+    fn ccc(&self, code: u32) -> u8
+    {
+        self.dataset
+            .unicode()
+            .get(&code)
+            .map_or(0, |codepoint| codepoint.ccc.u8())
+    }
+}
+
+/// продолжение последовательности в дереве весов
+fn child<'a>(node: &'a TrieNode, code: Option<&u32>) -> Option<&'a TrieNode>
+{
+    node.children.as_ref()?.get(code?)
+}
+
+/// кодпоинты строки
+fn codes(text: &str) -> Vec<u32>
+{
+    text.chars().map(|c| c as u32).collect()
+}
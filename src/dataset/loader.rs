@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::{Dataset, SourceFile, Sources, UnicodeVersion, NO_SOURCES};
+
+/// загрузка набора данных из файлов во время выполнения, вместо встроенных в бинарник данных
+/// каждый файл можно задать путём, через Read или загрузить все известные файлы каталога
+pub struct DatasetLoader
+{
+    version: Option<UnicodeVersion>,
+    sources: Sources,
+}
+
+impl Default for DatasetLoader
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl DatasetLoader
+{
+    /// пустой загрузчик: файлов, которые не будут указаны, в наборе данных нет - их таблицы
+    /// возвращают ошибку ParseErrorKind::MissingSource
+    pub fn new() -> Self
+    {
+        Self {
+            version: None,
+            sources: NO_SOURCES,
+        }
+    }
+
+    /// загрузчик, изначально содержащий встроенные данные указанной версии; набор данных
+    /// сохраняет эту версию
+    #[cfg(feature = "embedded")]
+    pub fn embedded(version: UnicodeVersion) -> Self
+    {
+        Self {
+            version: Some(version),
+            sources: version.dataset().sources.clone(),
+        }
+    }
+
+    /// загрузить из каталога (UCD, UCA или CLDR) все известные файлы-источники, которые в нём есть
+    pub fn directory(mut self, path: impl AsRef<Path>) -> io::Result<Self>
+    {
+        let path = path.as_ref();
+
+        if !path.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{}: каталог не найден", path.display()),
+            ));
+        }
+
+        for file in SourceFile::ALL {
+            let found = file
+                .file_names()
+                .iter()
+                .map(|name| path.join(name))
+                .find(|path| path.is_file());
+
+            if let Some(found) = found {
+                self = self.file(file, found)?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// загрузить файл-источник по пути
+    pub fn file(self, file: SourceFile, path: impl AsRef<Path>) -> io::Result<Self>
+    {
+        let text = std::fs::read_to_string(path)?;
+
+        Ok(self.text(file, text))
+    }
+
+    /// прочитать файл-источник из Read
+    pub fn reader(self, file: SourceFile, mut reader: impl Read) -> io::Result<Self>
+    {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        Ok(self.text(file, text))
+    }
+
+    /// задать текст файла-источника
+    pub fn text(mut self, file: SourceFile, text: impl Into<String>) -> Self
+    {
+        self.sources[file as usize] = Some(Cow::Owned(text.into()));
+        self
+    }
+
+    /// получить набор данных. файлы разбираются лениво, проверить их сразу можно через
+    /// Dataset::validate
+    pub fn load(self) -> Dataset
+    {
+        Dataset::new(self.version, self.sources)
+    }
+}
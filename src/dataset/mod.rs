@@ -0,0 +1,581 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::codepoint::{
+    BinaryProperty, Codepoint, EastAsianWidth, GraphemeClusterBreak, IndicConjunctBreak, LineBreak,
+    Script, SentenceBreak, WordBreak,
+};
+use crate::normalization::{
+    canonical_closure, combines_backwards, decomposition, fcd, pairs, precompositions,
+    CanonicalClosure, FcdClasses,
+};
+use crate::parse::*;
+
+mod loader;
+mod sources;
+
+pub use loader::DatasetLoader;
+pub use sources::SourceFile;
+
+use sources::*;
+
+/// упорядоченные диапазоны кодпоинтов бинарных свойств
+type BinaryPropertyRanges = HashMap<BinaryProperty, Vec<(u32, u32)>>;
+
+/// версия Unicode, для которой в data/ есть согласованный набор UCD + UCA + CLDR
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UnicodeVersion
+{
+    /// UCD 15.1.0, UCA 15.1.0, CLDR 44
+    V15_1_0,
+    /// UCD 17.0.0, UCA 17.0.0, CLDR 48
+    #[default]
+    V17_0_0,
+}
+
+impl UnicodeVersion
+{
+    /// все версии, данные которых есть в репозитории
+    pub const ALL: [Self; 2] = [Self::V15_1_0, Self::V17_0_0];
+
+    /// версия Unicode в виде строки, например "17.0.0"
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::V15_1_0 => "15.1.0",
+            Self::V17_0_0 => "17.0.0",
+        }
+    }
+
+    /// соответствующая версия CLDR
+    pub fn cldr(&self) -> u8
+    {
+        match self {
+            Self::V15_1_0 => 44,
+            Self::V17_0_0 => 48,
+        }
+    }
+
+    /// встроенный набор данных этой версии
+    #[cfg(feature = "embedded")]
+    pub fn dataset(&self) -> &'static Dataset
+    {
+        match self {
+            Self::V15_1_0 => &DATASET_15_1_0,
+            Self::V17_0_0 => &DATASET_17_0_0,
+        }
+    }
+}
+
+impl core::fmt::Display for UnicodeVersion
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "embedded")]
+static DATASET_15_1_0: Dataset = Dataset::new(Some(UnicodeVersion::V15_1_0), SOURCES_15_1_0);
+#[cfg(feature = "embedded")]
+static DATASET_17_0_0: Dataset = Dataset::new(Some(UnicodeVersion::V17_0_0), SOURCES_17_0_0);
+
+/// согласованный набор данных UCD, UCA и CLDR одной версии Unicode
+///
+/// таблицы строятся лениво, при первом обращении, и живут, пока жив набор данных.
+/// производные таблицы (декомпозиции, пары композиции, деревья весов) строятся из UCD той же версии.
+/// если файла-источника нет, try_* возвращают ошибку ParseErrorKind::MissingSource, а методы без
+/// try_ - паникуют
+pub struct Dataset
+{
+    version: Option<UnicodeVersion>,
+    sources: Sources,
+
+    unicode: OnceLock<HashMap<u32, Codepoint>>,
+    blocks: OnceLock<HashMap<String, CodepointsBlock>>,
+    qc_nfd: OnceLock<QuickCheckTable>,
+    qc_nfc: OnceLock<QuickCheckTable>,
+    qc_nfkd: OnceLock<QuickCheckTable>,
+    qc_nfkc: OnceLock<QuickCheckTable>,
+    composition_exclusions: OnceLock<Vec<u32>>,
+    nfkc_cf: OnceLock<HashMap<u32, Vec<u32>>>,
+    nfkc_scf: OnceLock<HashMap<u32, Vec<u32>>>,
+    fc_nfkc: OnceLock<HashMap<u32, Vec<u32>>>,
+    normalization_properties: OnceLock<HashMap<NormalizationProperty, Vec<(u32, u32)>>>,
+    normalization_tests: OnceLock<Vec<NormalizationTest>>,
+    scripts: OnceLock<Vec<(u32, u32, Script)>>,
+    scx: OnceLock<Vec<(u32, u32, Vec<Script>)>>,
+    binary_properties: OnceLock<BinaryPropertyRanges>,
+    incb: OnceLock<Vec<(u32, u32, IndicConjunctBreak)>>,
+    special_casing: OnceLock<HashMap<u32, Vec<SpecialCasing>>>,
+    case_folding: OnceLock<HashMap<u32, Vec<CaseFolding>>>,
+    gcb: OnceLock<Vec<(u32, u32, GraphemeClusterBreak)>>,
+    grapheme_break_tests: OnceLock<Vec<BreakTest>>,
+    wb: OnceLock<Vec<(u32, u32, WordBreak)>>,
+    sb: OnceLock<Vec<(u32, u32, SentenceBreak)>>,
+    word_break_tests: OnceLock<Vec<BreakTest>>,
+    sentence_break_tests: OnceLock<Vec<BreakTest>>,
+    ea: OnceLock<Vec<(u32, u32, EastAsianWidth)>>,
+    lb: OnceLock<Vec<(u32, u32, LineBreak)>>,
+    line_break_tests: OnceLock<Vec<BreakTest>>,
+
+    nfd: OnceLock<HashMap<u32, Vec<Codepoint>>>,
+    nfkd: OnceLock<HashMap<u32, Vec<Codepoint>>>,
+    nfc: OnceLock<HashMap<u32, Vec<Codepoint>>>,
+    nfkc: OnceLock<HashMap<u32, Vec<Codepoint>>>,
+    composition_pairs: OnceLock<HashMap<u32, HashMap<u32, Codepoint>>>,
+    combines_backwards: OnceLock<HashMap<u32, HashMap<u32, Codepoint>>>,
+    fcd: OnceLock<HashMap<u32, FcdClasses>>,
+    canonical_closure: OnceLock<CanonicalClosure>,
+
+    ducet: OnceLock<Vec<WeightsEntry>>,
+    ducet_trie: OnceLock<HashMap<u32, TrieNode>>,
+    implicit_weights: OnceLock<Vec<ImplicitWeights>>,
+    cldr_und: OnceLock<Vec<WeightsEntry>>,
+    cldr_und_trie: OnceLock<HashMap<u32, TrieNode>>,
+    fractional_uca: OnceLock<FractionalUca>,
+
+    collation_test_ducet_non_ignorable: OnceLock<Vec<CollationTest>>,
+    collation_test_ducet_shifted: OnceLock<Vec<CollationTest>>,
+    collation_test_cldr_non_ignorable: OnceLock<Vec<CollationTest>>,
+    collation_test_cldr_shifted: OnceLock<Vec<CollationTest>>,
+}
+
+/// ленивое получение таблицы набора данных
+macro_rules! table {
+    ($(#[$meta:meta])* $field:ident: $type:ty = $init:expr) => {
+        $(#[$meta])*
+        pub fn $field(&self) -> &$type
+        {
+            self.$field.get_or_init(|| $init(self))
+        }
+    };
+}
+
+/// ленивое получение таблицы, разбираемой из файла-источника: $field паникует при ошибке разбора,
+/// $try_field - возвращает ошибку
+macro_rules! parsed_table {
+    ($(#[$meta:meta])* $field:ident, $try_field:ident: $type:ty = $init:expr) => {
+        $(#[$meta])*
+        pub fn $field(&self) -> &$type
+        {
+            match self.$try_field() {
+                Ok(table) => table,
+                Err(error) => panic!("{}", error),
+            }
+        }
+
+        $(#[$meta])*
+        pub fn $try_field(&self) -> Result<&$type, ParseError>
+        {
+            if let Some(table) = self.$field.get() {
+                return Ok(table);
+            }
+
+            let table = $init(self)?;
+
+            Ok(self.$field.get_or_init(|| table))
+        }
+    };
+}
+
+impl Dataset
+{
+    pub(crate) const fn new(version: Option<UnicodeVersion>, sources: Sources) -> Self
+    {
+        Self {
+            version,
+            sources,
+            unicode: OnceLock::new(),
+            blocks: OnceLock::new(),
+            qc_nfd: OnceLock::new(),
+            qc_nfc: OnceLock::new(),
+            qc_nfkd: OnceLock::new(),
+            qc_nfkc: OnceLock::new(),
+            composition_exclusions: OnceLock::new(),
+            nfkc_cf: OnceLock::new(),
+            nfkc_scf: OnceLock::new(),
+            fc_nfkc: OnceLock::new(),
+            normalization_properties: OnceLock::new(),
+            normalization_tests: OnceLock::new(),
+            scripts: OnceLock::new(),
+            scx: OnceLock::new(),
+            binary_properties: OnceLock::new(),
+            incb: OnceLock::new(),
+            special_casing: OnceLock::new(),
+            case_folding: OnceLock::new(),
+            gcb: OnceLock::new(),
+            grapheme_break_tests: OnceLock::new(),
+            wb: OnceLock::new(),
+            sb: OnceLock::new(),
+            word_break_tests: OnceLock::new(),
+            sentence_break_tests: OnceLock::new(),
+            ea: OnceLock::new(),
+            lb: OnceLock::new(),
+            line_break_tests: OnceLock::new(),
+            nfd: OnceLock::new(),
+            nfkd: OnceLock::new(),
+            nfc: OnceLock::new(),
+            nfkc: OnceLock::new(),
+            composition_pairs: OnceLock::new(),
+            combines_backwards: OnceLock::new(),
+            fcd: OnceLock::new(),
+            canonical_closure: OnceLock::new(),
+            ducet: OnceLock::new(),
+            ducet_trie: OnceLock::new(),
+            implicit_weights: OnceLock::new(),
+            cldr_und: OnceLock::new(),
+            cldr_und_trie: OnceLock::new(),
+            fractional_uca: OnceLock::new(),
+            collation_test_ducet_non_ignorable: OnceLock::new(),
+            collation_test_ducet_shifted: OnceLock::new(),
+            collation_test_cldr_non_ignorable: OnceLock::new(),
+            collation_test_cldr_shifted: OnceLock::new(),
+        }
+    }
+
+    /// версия Unicode встроенного набора данных или загруженного через DatasetLoader::embedded,
+    /// None - для загруженного во время выполнения без встроенных данных
+    pub fn version(&self) -> Option<UnicodeVersion>
+    {
+        self.version
+    }
+
+    /// текст файла-источника, ошибка MissingSource - если файла нет
+    pub(crate) fn source(&self, file: SourceFile) -> Result<&str, ParseError>
+    {
+        self.sources[file as usize]
+            .as_deref()
+            .ok_or_else(|| ParseError::missing_source(file, ""))
+    }
+
+    /// есть ли в наборе данных файл-источник
+    pub fn has_source(&self, file: SourceFile) -> bool
+    {
+        self.sources[file as usize].is_some()
+    }
+
+    /// разобрать все файлы-источники, вернуть первую ошибку разбора или отсутствующий файл
+    pub fn validate(&self) -> Result<(), ParseError>
+    {
+        self.try_unicode()?;
+        self.try_blocks()?;
+        self.try_qc_nfd()?;
+        self.try_qc_nfc()?;
+        self.try_qc_nfkd()?;
+        self.try_qc_nfkc()?;
+        self.try_composition_exclusions()?;
+        self.try_nfkc_cf()?;
+        self.try_nfkc_scf()?;
+        self.try_fc_nfkc()?;
+        self.try_normalization_properties()?;
+        self.try_normalization_tests()?;
+        self.try_scripts()?;
+        self.try_scx()?;
+        self.try_binary_properties()?;
+        self.try_incb()?;
+        self.try_special_casing()?;
+        self.try_case_folding()?;
+        self.try_gcb()?;
+        self.try_grapheme_break_tests()?;
+        self.try_wb()?;
+        self.try_sb()?;
+        self.try_word_break_tests()?;
+        self.try_sentence_break_tests()?;
+        self.try_ea()?;
+        self.try_lb()?;
+        self.try_line_break_tests()?;
+        self.try_ducet()?;
+        self.try_implicit_weights()?;
+        self.try_cldr_und()?;
+        self.try_fractional_uca()?;
+        self.try_collation_test_ducet_non_ignorable()?;
+        self.try_collation_test_ducet_shifted()?;
+        self.try_collation_test_cldr_non_ignorable()?;
+        self.try_collation_test_cldr_shifted()?;
+
+        Ok(())
+    }
+
+    parsed_table!(
+        /// таблица Unicode (UnicodeData.txt)
+        unicode, try_unicode: HashMap<u32, Codepoint> =
+            |d: &Dataset| unicode(d.source(SourceFile::UnicodeData)?)
+    );
+    parsed_table!(
+        /// блоки кодпоинтов (Blocks.txt)
+        blocks, try_blocks: HashMap<String, CodepointsBlock> =
+            |d: &Dataset| blocks(d.source(SourceFile::Blocks)?)
+    );
+    parsed_table!(
+        /// быстрые проверки NFD, Y/N
+        qc_nfd, try_qc_nfd: QuickCheckTable =
+            |d: &Dataset| nfd_qc(d.source(SourceFile::DerivedNormalizationProps)?)
+    );
+    parsed_table!(
+        /// быстрые проверки NFC, Y/N/M
+        qc_nfc, try_qc_nfc: QuickCheckTable =
+            |d: &Dataset| nfc_qc(d.source(SourceFile::DerivedNormalizationProps)?)
+    );
+    parsed_table!(
+        /// быстрые проверки NFKD, Y/N
+        qc_nfkd, try_qc_nfkd: QuickCheckTable =
+            |d: &Dataset| nfkd_qc(d.source(SourceFile::DerivedNormalizationProps)?)
+    );
+    parsed_table!(
+        /// быстрые проверки NFKC, Y/N/M
+        qc_nfkc, try_qc_nfkc: QuickCheckTable =
+            |d: &Dataset| nfkc_qc(d.source(SourceFile::DerivedNormalizationProps)?)
+    );
+    parsed_table!(
+        /// исключения композиции (CompositionExclusions.txt)
+        composition_exclusions, try_composition_exclusions: Vec<u32> =
+            |d: &Dataset| composition_exclusions(d.source(SourceFile::CompositionExclusions)?)
+    );
+    parsed_table!(
+        /// NFKC_Casefold (DerivedNormalizationProps.txt)
+        nfkc_cf, try_nfkc_cf: HashMap<u32, Vec<u32>> = |d: &Dataset| {
+            derived_mapping(d.source(SourceFile::DerivedNormalizationProps)?, "NFKC_CF")
+        }
+    );
+    parsed_table!(
+        /// NFKC_Simple_Casefold (DerivedNormalizationProps.txt)
+        nfkc_scf, try_nfkc_scf: HashMap<u32, Vec<u32>> = |d: &Dataset| {
+            derived_mapping(d.source(SourceFile::DerivedNormalizationProps)?, "NFKC_SCF")
+        }
+    );
+    parsed_table!(
+        /// FC_NFKC_Closure (DerivedNormalizationProps.txt)
+        fc_nfkc, try_fc_nfkc: HashMap<u32, Vec<u32>> = |d: &Dataset| {
+            derived_mapping(d.source(SourceFile::DerivedNormalizationProps)?, "FC_NFKC")
+        }
+    );
+    parsed_table!(
+        /// бинарные свойства DerivedNormalizationProps.txt
+        normalization_properties, try_normalization_properties:
+            HashMap<NormalizationProperty, Vec<(u32, u32)>> = |d: &Dataset| {
+                derived_properties(d.source(SourceFile::DerivedNormalizationProps)?)
+            }
+    );
+    parsed_table!(
+        /// тесты нормализации (NormalizationTest.txt)
+        normalization_tests, try_normalization_tests: Vec<NormalizationTest> =
+            |d: &Dataset| normalization_tests(d.source(SourceFile::NormalizationTest)?)
+    );
+    parsed_table!(
+        /// письменности кодпоинтов (Scripts.txt)
+        scripts, try_scripts: Vec<(u32, u32, Script)> =
+            |d: &Dataset| scripts(d.source(SourceFile::Scripts)?)
+    );
+    parsed_table!(
+        /// расширения письменностей кодпоинтов (ScriptExtensions.txt)
+        scx, try_scx: Vec<(u32, u32, Vec<Script>)> =
+            |d: &Dataset| script_extensions(d.source(SourceFile::ScriptExtensions)?)
+    );
+    /// бинарные свойства (PropList.txt, DerivedCoreProperties.txt, emoji-data.txt)
+    pub fn binary_properties(&self) -> &BinaryPropertyRanges
+    {
+        match self.try_binary_properties() {
+            Ok(table) => table,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// бинарные свойства (PropList.txt, DerivedCoreProperties.txt, emoji-data.txt); ошибка
+    /// MissingSource - если нет хотя бы одного из файлов
+    pub fn try_binary_properties(&self) -> Result<&BinaryPropertyRanges, ParseError>
+    {
+        for file in [
+            SourceFile::PropList,
+            SourceFile::DerivedCoreProperties,
+            SourceFile::EmojiData,
+        ] {
+            self.source(file)?;
+        }
+
+        self.try_available_binary_properties()
+    }
+
+    /// бинарные свойства из имеющихся файлов-источников, наличие файла конкретного свойства
+    /// проверяет try_has_property
+    pub(crate) fn try_available_binary_properties(
+        &self,
+    ) -> Result<&BinaryPropertyRanges, ParseError>
+    {
+        if let Some(table) = self.binary_properties.get() {
+            return Ok(table);
+        }
+
+        let source = |file| self.source(file).unwrap_or_default();
+        let table = binary_properties(
+            source(SourceFile::PropList),
+            source(SourceFile::DerivedCoreProperties),
+            source(SourceFile::EmojiData),
+        )?;
+
+        Ok(self.binary_properties.get_or_init(|| table))
+    }
+
+    parsed_table!(
+        /// Indic_Conjunct_Break (DerivedCoreProperties.txt)
+        incb, try_incb: Vec<(u32, u32, IndicConjunctBreak)> =
+            |d: &Dataset| indic_conjunct_break(d.source(SourceFile::DerivedCoreProperties)?)
+    );
+    parsed_table!(
+        /// отображения регистра (SpecialCasing.txt)
+        special_casing, try_special_casing: HashMap<u32, Vec<SpecialCasing>> =
+            |d: &Dataset| special_casing(d.source(SourceFile::SpecialCasing)?)
+    );
+    parsed_table!(
+        /// свёртка регистра (CaseFolding.txt)
+        case_folding, try_case_folding: HashMap<u32, Vec<CaseFolding>> =
+            |d: &Dataset| case_folding(d.source(SourceFile::CaseFolding)?)
+    );
+    parsed_table!(
+        /// Grapheme_Cluster_Break (GraphemeBreakProperty.txt)
+        gcb, try_gcb: Vec<(u32, u32, GraphemeClusterBreak)> =
+            |d: &Dataset| grapheme_cluster_break(d.source(SourceFile::GraphemeBreakProperty)?)
+    );
+    parsed_table!(
+        /// тесты сегментации графем (GraphemeBreakTest.txt)
+        grapheme_break_tests, try_grapheme_break_tests: Vec<BreakTest> = |d: &Dataset| {
+            break_tests(SourceFile::GraphemeBreakTest, d.source(SourceFile::GraphemeBreakTest)?)
+        }
+    );
+    parsed_table!(
+        /// Word_Break (WordBreakProperty.txt)
+        wb, try_wb: Vec<(u32, u32, WordBreak)> =
+            |d: &Dataset| word_break(d.source(SourceFile::WordBreakProperty)?)
+    );
+    parsed_table!(
+        /// Sentence_Break (SentenceBreakProperty.txt)
+        sb, try_sb: Vec<(u32, u32, SentenceBreak)> =
+            |d: &Dataset| sentence_break(d.source(SourceFile::SentenceBreakProperty)?)
+    );
+    parsed_table!(
+        /// тесты границ слов (WordBreakTest.txt)
+        word_break_tests, try_word_break_tests: Vec<BreakTest> = |d: &Dataset| {
+            break_tests(SourceFile::WordBreakTest, d.source(SourceFile::WordBreakTest)?)
+        }
+    );
+    parsed_table!(
+        /// тесты границ предложений (SentenceBreakTest.txt)
+        sentence_break_tests, try_sentence_break_tests: Vec<BreakTest> = |d: &Dataset| {
+            break_tests(SourceFile::SentenceBreakTest, d.source(SourceFile::SentenceBreakTest)?)
+        }
+    );
+    parsed_table!(
+        /// East_Asian_Width (EastAsianWidth.txt)
+        ea, try_ea: Vec<(u32, u32, EastAsianWidth)> =
+            |d: &Dataset| east_asian_width(d.source(SourceFile::EastAsianWidth)?)
+    );
+    parsed_table!(
+        /// Line_Break (LineBreak.txt)
+        lb, try_lb: Vec<(u32, u32, LineBreak)> =
+            |d: &Dataset| line_break(d.source(SourceFile::LineBreak)?)
+    );
+    parsed_table!(
+        /// тесты разрыва строк (LineBreakTest.txt)
+        line_break_tests, try_line_break_tests: Vec<BreakTest> = |d: &Dataset| {
+            break_tests(SourceFile::LineBreakTest, d.source(SourceFile::LineBreakTest)?)
+        }
+    );
+
+    table!(
+        /// таблица декомпозиций NFD
+        nfd: HashMap<u32, Vec<Codepoint>> = |d| decomposition(d, true)
+    );
+    table!(
+        /// таблица декомпозиций NFKD
+        nfkd: HashMap<u32, Vec<Codepoint>> = |d| decomposition(d, false)
+    );
+    table!(
+        /// таблица композиций NFC
+        nfc: HashMap<u32, Vec<Codepoint>> = |d| precompositions(d, true)
+    );
+    table!(
+        /// таблица композиций NFKC
+        nfkc: HashMap<u32, Vec<Codepoint>> = |d| precompositions(d, false)
+    );
+    table!(
+        /// комбинируемые пары кодпоинтов
+        composition_pairs: HashMap<u32, HashMap<u32, Codepoint>> = pairs
+    );
+    table!(
+        /// кодпоинты, комбинируемые с предыдущими
+        combines_backwards: HashMap<u32, HashMap<u32, Codepoint>> = combines_backwards
+    );
+    table!(
+        /// lead / trail CCC канонических декомпозиций (FCD)
+        fcd: HashMap<u32, FcdClasses> = fcd
+    );
+    table!(
+        /// каноническое замыкание: NFD-декомпозиция -> прекомпозированные кодпоинты
+        canonical_closure: CanonicalClosure = canonical_closure
+    );
+
+    parsed_table!(
+        /// таблица DUCET из allkeys.txt
+        ducet, try_ducet: Vec<WeightsEntry> =
+            |d: &Dataset| allkeys(SourceFile::Allkeys, d.source(SourceFile::Allkeys)?)
+    );
+    table!(
+        /// DUCET в виде дерева
+        ducet_trie: HashMap<u32, TrieNode> = |d: &Dataset| weights_trie(d.ducet())
+    );
+    parsed_table!(
+        /// диапазоны вычисляемых весов (@implicitweights) из allkeys.txt
+        implicit_weights, try_implicit_weights: Vec<ImplicitWeights> =
+            |d: &Dataset| implicit_weights(SourceFile::Allkeys, d.source(SourceFile::Allkeys)?)
+    );
+    parsed_table!(
+        /// таблица DUCET из allkeys_CLDR.txt, адаптированная для CLDR
+        cldr_und, try_cldr_und: Vec<WeightsEntry> =
+            |d: &Dataset| allkeys(SourceFile::AllkeysCldr, d.source(SourceFile::AllkeysCldr)?)
+    );
+    table!(
+        /// CLDR UND в виде дерева
+        cldr_und_trie: HashMap<u32, TrieNode> = |d: &Dataset| weights_trie(d.cldr_und())
+    );
+    parsed_table!(
+        /// таблица Fractional UCA
+        fractional_uca, try_fractional_uca: FractionalUca =
+            |d: &Dataset| fractional_table(d.source(SourceFile::FractionalUca)?)
+    );
+
+    parsed_table!(
+        /// тесты сопоставлений DUCET, NON_IGNORABLE
+        collation_test_ducet_non_ignorable, try_collation_test_ducet_non_ignorable: Vec<CollationTest> =
+            |d: &Dataset| d.collation_test(SourceFile::CollationTestDucetNonIgnorable)
+    );
+    parsed_table!(
+        /// тесты сопоставлений DUCET, SHIFTED
+        collation_test_ducet_shifted, try_collation_test_ducet_shifted: Vec<CollationTest> =
+            |d: &Dataset| d.collation_test(SourceFile::CollationTestDucetShifted)
+    );
+    parsed_table!(
+        /// тесты сопоставлений CLDR, NON_IGNORABLE
+        collation_test_cldr_non_ignorable, try_collation_test_cldr_non_ignorable: Vec<CollationTest> =
+            |d: &Dataset| d.collation_test(SourceFile::CollationTestCldrNonIgnorable)
+    );
+    parsed_table!(
+        /// тесты сопоставлений CLDR, SHIFTED
+        collation_test_cldr_shifted, try_collation_test_cldr_shifted: Vec<CollationTest> =
+            |d: &Dataset| d.collation_test(SourceFile::CollationTestCldrShifted)
+    );
+
+    fn collation_test(&self, file: SourceFile) -> Result<Vec<CollationTest>, ParseError>
+    {
+        parse_collation_test(file, self.source(file)?)
+    }
+}
+
+impl core::fmt::Debug for Dataset
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_struct("Dataset")
+            .field("version", &self.version)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,243 @@
+use std::borrow::Cow;
+
+/// файл UCD / UCA / CLDR, из которого строятся таблицы набора данных
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceFile
+{
+    /// UCD: UnicodeData.txt
+    UnicodeData,
+    /// UCD: Blocks.txt
+    Blocks,
+    /// UCD: CompositionExclusions.txt
+    CompositionExclusions,
+    /// UCD: DerivedNormalizationProps.txt
+    DerivedNormalizationProps,
+    /// UCD: NormalizationTest.txt
+    NormalizationTest,
+    /// UCA: allkeys.txt
+    Allkeys,
+    /// CLDR: allkeys_CLDR.txt
+    AllkeysCldr,
+    /// CLDR: FractionalUCA(_SHORT).txt
+    FractionalUca,
+    /// UCA: CollationTest/CollationTest_NON_IGNORABLE(_SHORT).txt
+    CollationTestDucetNonIgnorable,
+    /// UCA: CollationTest/CollationTest_SHIFTED(_SHORT).txt
+    CollationTestDucetShifted,
+    /// CLDR: CollationTest/CollationTest_CLDR_NON_IGNORABLE(_SHORT).txt
+    CollationTestCldrNonIgnorable,
+    /// CLDR: CollationTest/CollationTest_CLDR_SHIFTED(_SHORT).txt
+    CollationTestCldrShifted,
+    /// UCD: Scripts.txt
+    Scripts,
+    /// UCD: ScriptExtensions.txt
+    ScriptExtensions,
+    /// UCD: PropList.txt
+    PropList,
+    /// UCD: DerivedCoreProperties.txt
+    DerivedCoreProperties,
+    /// UCD: SpecialCasing.txt
+    SpecialCasing,
+    /// UCD: CaseFolding.txt
+    CaseFolding,
+    /// UCD: auxiliary/GraphemeBreakProperty.txt
+    GraphemeBreakProperty,
+    /// UCD: emoji/emoji-data.txt
+    EmojiData,
+    /// UCD: auxiliary/GraphemeBreakTest.txt
+    GraphemeBreakTest,
+    /// UCD: auxiliary/WordBreakProperty.txt
+    WordBreakProperty,
+    /// UCD: auxiliary/SentenceBreakProperty.txt
+    SentenceBreakProperty,
+    /// UCD: auxiliary/WordBreakTest.txt
+    WordBreakTest,
+    /// UCD: auxiliary/SentenceBreakTest.txt
+    SentenceBreakTest,
+    /// UCD: EastAsianWidth.txt
+    EastAsianWidth,
+    /// UCD: LineBreak.txt
+    LineBreak,
+    /// UCD: auxiliary/LineBreakTest.txt
+    LineBreakTest,
+}
+
+impl SourceFile
+{
+    /// количество файлов-источников
+    pub const COUNT: usize = 28;
+
+    /// все файлы-источники
+    pub const ALL: [Self; Self::COUNT] = [
+        Self::UnicodeData,
+        Self::Blocks,
+        Self::CompositionExclusions,
+        Self::DerivedNormalizationProps,
+        Self::NormalizationTest,
+        Self::Allkeys,
+        Self::AllkeysCldr,
+        Self::FractionalUca,
+        Self::CollationTestDucetNonIgnorable,
+        Self::CollationTestDucetShifted,
+        Self::CollationTestCldrNonIgnorable,
+        Self::CollationTestCldrShifted,
+        Self::Scripts,
+        Self::ScriptExtensions,
+        Self::PropList,
+        Self::DerivedCoreProperties,
+        Self::SpecialCasing,
+        Self::CaseFolding,
+        Self::GraphemeBreakProperty,
+        Self::EmojiData,
+        Self::GraphemeBreakTest,
+        Self::WordBreakProperty,
+        Self::SentenceBreakProperty,
+        Self::WordBreakTest,
+        Self::SentenceBreakTest,
+        Self::EastAsianWidth,
+        Self::LineBreak,
+        Self::LineBreakTest,
+    ];
+
+    /// пути к файлу относительно каталога UCD / UCA / CLDR, в порядке предпочтения
+    pub fn file_names(&self) -> &'static [&'static str]
+    {
+        match self {
+            Self::UnicodeData => &["UnicodeData.txt"],
+            Self::Blocks => &["Blocks.txt"],
+            Self::CompositionExclusions => &["CompositionExclusions.txt"],
+            Self::DerivedNormalizationProps => &["DerivedNormalizationProps.txt"],
+            Self::NormalizationTest => &["NormalizationTest.txt"],
+            Self::Allkeys => &["allkeys.txt"],
+            Self::AllkeysCldr => &["allkeys_CLDR.txt"],
+            Self::FractionalUca => &["FractionalUCA.txt", "FractionalUCA_SHORT.txt"],
+            Self::CollationTestDucetNonIgnorable => &[
+                "CollationTest/CollationTest_NON_IGNORABLE.txt",
+                "CollationTest/CollationTest_NON_IGNORABLE_SHORT.txt",
+            ],
+            Self::CollationTestDucetShifted => &[
+                "CollationTest/CollationTest_SHIFTED.txt",
+                "CollationTest/CollationTest_SHIFTED_SHORT.txt",
+            ],
+            Self::CollationTestCldrNonIgnorable => &[
+                "CollationTest/CollationTest_CLDR_NON_IGNORABLE.txt",
+                "CollationTest/CollationTest_CLDR_NON_IGNORABLE_SHORT.txt",
+            ],
+            Self::CollationTestCldrShifted => &[
+                "CollationTest/CollationTest_CLDR_SHIFTED.txt",
+                "CollationTest/CollationTest_CLDR_SHIFTED_SHORT.txt",
+            ],
+            Self::Scripts => &["Scripts.txt"],
+            Self::ScriptExtensions => &["ScriptExtensions.txt"],
+            Self::PropList => &["PropList.txt"],
+            Self::DerivedCoreProperties => &["DerivedCoreProperties.txt"],
+            Self::SpecialCasing => &["SpecialCasing.txt"],
+            Self::CaseFolding => &["CaseFolding.txt"],
+            Self::GraphemeBreakProperty => &[
+                "auxiliary/GraphemeBreakProperty.txt",
+                "GraphemeBreakProperty.txt",
+            ],
+            Self::EmojiData => &["emoji/emoji-data.txt", "emoji-data.txt"],
+            Self::GraphemeBreakTest => {
+                &["auxiliary/GraphemeBreakTest.txt", "GraphemeBreakTest.txt"]
+            }
+            Self::WordBreakProperty => {
+                &["auxiliary/WordBreakProperty.txt", "WordBreakProperty.txt"]
+            }
+            Self::SentenceBreakProperty => &[
+                "auxiliary/SentenceBreakProperty.txt",
+                "SentenceBreakProperty.txt",
+            ],
+            Self::WordBreakTest => &["auxiliary/WordBreakTest.txt", "WordBreakTest.txt"],
+            Self::SentenceBreakTest => {
+                &["auxiliary/SentenceBreakTest.txt", "SentenceBreakTest.txt"]
+            }
+            Self::EastAsianWidth => &["EastAsianWidth.txt"],
+            Self::LineBreak => &["LineBreak.txt"],
+            Self::LineBreakTest => &["auxiliary/LineBreakTest.txt", "LineBreakTest.txt"],
+        }
+    }
+}
+
+/// исходные тексты файлов UCD, UCA и CLDR одного набора данных, по индексу SourceFile
+pub(crate) type Sources = [Option<Cow<'static, str>>; SourceFile::COUNT];
+
+/// пустой набор исходных текстов
+pub(crate) const NO_SOURCES: Sources = [const { None }; SourceFile::COUNT];
+
+#[cfg(feature = "embedded")]
+macro_rules! embedded {
+    ($path:expr) => {
+        Some(Cow::Borrowed(include_str!(concat!("./../../data/", $path))))
+    };
+}
+
+/// встроенные тексты: каталоги UCD, UCA, CLDR, тесты сопоставлений и LineBreakTest.txt,
+/// в порядке SourceFile::ALL
+#[cfg(feature = "embedded")]
+macro_rules! embedded_sources {
+    (
+        $ucd:literal,
+        $uca:literal,
+        $cldr:literal,
+        [$($collation_test:expr),*],
+        $line_break_test:expr
+    ) => {
+        [
+            embedded!(concat!($ucd, "/UnicodeData.txt")),
+            embedded!(concat!($ucd, "/Blocks.txt")),
+            embedded!(concat!($ucd, "/CompositionExclusions.txt")),
+            embedded!(concat!($ucd, "/DerivedNormalizationProps.txt")),
+            embedded!(concat!($ucd, "/NormalizationTest.txt")),
+            embedded!(concat!($uca, "/allkeys.txt")),
+            embedded!(concat!($cldr, "/allkeys_CLDR.txt")),
+            embedded!(concat!($cldr, "/FractionalUCA_SHORT.txt")),
+            $($collation_test,)*
+            embedded!(concat!($ucd, "/Scripts.txt")),
+            embedded!(concat!($ucd, "/ScriptExtensions.txt")),
+            embedded!(concat!($ucd, "/PropList.txt")),
+            embedded!(concat!($ucd, "/DerivedCoreProperties.txt")),
+            embedded!(concat!($ucd, "/SpecialCasing.txt")),
+            embedded!(concat!($ucd, "/CaseFolding.txt")),
+            embedded!(concat!($ucd, "/auxiliary/GraphemeBreakProperty.txt")),
+            embedded!(concat!($ucd, "/emoji/emoji-data.txt")),
+            embedded!(concat!($ucd, "/auxiliary/GraphemeBreakTest.txt")),
+            embedded!(concat!($ucd, "/auxiliary/WordBreakProperty.txt")),
+            embedded!(concat!($ucd, "/auxiliary/SentenceBreakProperty.txt")),
+            embedded!(concat!($ucd, "/auxiliary/WordBreakTest.txt")),
+            embedded!(concat!($ucd, "/auxiliary/SentenceBreakTest.txt")),
+            embedded!(concat!($ucd, "/EastAsianWidth.txt")),
+            embedded!(concat!($ucd, "/LineBreak.txt")),
+            $line_break_test,
+        ]
+    };
+}
+
+/// для UCA 15.1.0 тестов сопоставлений DUCET и LineBreakTest.txt 15.1.0 в data/ нет
+#[cfg(feature = "embedded")]
+pub(crate) const SOURCES_15_1_0: Sources = embedded_sources!(
+    "ucd 15.1.0",
+    "uca 15.1.0",
+    "cldr 44",
+    [
+        None,
+        None,
+        embedded!("cldr 44/CollationTest/CollationTest_CLDR_NON_IGNORABLE_SHORT.txt"),
+        embedded!("cldr 44/CollationTest/CollationTest_CLDR_SHIFTED_SHORT.txt")
+    ],
+    None
+);
+
+#[cfg(feature = "embedded")]
+pub(crate) const SOURCES_17_0_0: Sources = embedded_sources!(
+    "ucd 17.0.0",
+    "uca 17.0.0",
+    "cldr 48",
+    [
+        embedded!("uca 17.0.0/CollationTest/CollationTest_NON_IGNORABLE_SHORT.txt"),
+        embedded!("uca 17.0.0/CollationTest/CollationTest_SHIFTED_SHORT.txt"),
+        embedded!("cldr 48/CollationTest/CollationTest_CLDR_NON_IGNORABLE_SHORT.txt"),
+        embedded!("cldr 48/CollationTest/CollationTest_CLDR_SHIFTED_SHORT.txt")
+    ],
+    embedded!("ucd 17.0.0/auxiliary/LineBreakTest.txt")
+);
@@ -1,9 +1,18 @@
+#[cfg(feature = "embedded")]
 #[macro_use]
 extern crate lazy_static;
 
+mod casing;
 pub mod codepoint;
+mod collation;
+mod dataset;
 mod normalization;
 mod parse;
+mod segmentation;
 
+pub use casing::*;
+pub use collation::*;
+pub use dataset::*;
 pub use normalization::*;
 pub use parse::*;
+pub use segmentation::*;
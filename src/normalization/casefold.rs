@@ -0,0 +1,93 @@
+use crate::codepoint::Codepoint;
+use crate::normalization::decompose;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, NormalizationForm, NormalizationProperty, Normalizer};
+
+// NFKC_Casefold, UAX #44, 5.7.4 / The Unicode Standard, 3.13, R5:
+// toNFKC_Casefold(X) = NFC(отображение NFKC_CF каждого кодпоинта NFD(X)) - отображения отдельных
+// кодпоинтов уже нормализованы, но соседние кодпоинты могут комбинироваться
+
+/// NFKC_Casefold строки - для сравнения идентификаторов
+#[cfg(feature = "embedded")]
+pub fn nfkc_casefold(text: &str) -> String
+{
+    UnicodeVersion::default().dataset().nfkc_casefold(text)
+}
+
+/// NFKC_Simple_Casefold строки
+#[cfg(feature = "embedded")]
+pub fn nfkc_simple_casefold(text: &str) -> String
+{
+    UnicodeVersion::default()
+        .dataset()
+        .nfkc_simple_casefold(text)
+}
+
+impl Dataset
+{
+    /// отображение NFKC_CF кодпоинта
+    pub fn nfkc_casefold_mapping(&self, code: u32) -> Vec<u32>
+    {
+        self.nfkc_cf().get(&code).cloned().unwrap_or(vec![code])
+    }
+
+    /// отображение NFKC_SCF кодпоинта
+    pub fn nfkc_simple_casefold_mapping(&self, code: u32) -> Vec<u32>
+    {
+        self.nfkc_scf().get(&code).cloned().unwrap_or(vec![code])
+    }
+
+    /// изменяется ли кодпоинт при NFKC_Casefold (CWKCF)
+    pub fn changes_when_nfkc_casefolded(&self, code: u32) -> bool
+    {
+        self.has_normalization_property(code, NormalizationProperty::ChangesWhenNfkcCasefolded)
+    }
+
+    /// NFKC_Casefold строки
+    pub fn nfkc_casefold(&self, text: &str) -> String
+    {
+        self.casefold(text, |code| self.nfkc_casefold_mapping(code))
+    }
+
+    /// NFKC_Simple_Casefold строки
+    pub fn nfkc_simple_casefold(&self, text: &str) -> String
+    {
+        self.casefold(text, |code| self.nfkc_simple_casefold_mapping(code))
+    }
+
+    /// отобразить кодпоинты NFD-формы строки и привести результат к NFC
+    fn casefold(&self, text: &str, mapping: impl Fn(u32) -> Vec<u32>) -> String
+    {
+        let codes: Vec<u32> = text.chars().map(u32::from).collect();
+
+        let mapped: String = decompose(self, &codes, true)
+            .into_iter()
+            .flat_map(mapping)
+            .filter_map(char::from_u32)
+            .collect();
+
+        Normalizer::new(self, NormalizationForm::Nfc).normalize(&mapped)
+    }
+}
+
+impl Codepoint
+{
+    /// отображение NFKC_CF
+    pub fn nfkc_casefold(&self, dataset: &Dataset) -> Vec<u32>
+    {
+        dataset.nfkc_casefold_mapping(self.code)
+    }
+
+    /// отображение NFKC_SCF
+    pub fn nfkc_simple_casefold(&self, dataset: &Dataset) -> Vec<u32>
+    {
+        dataset.nfkc_simple_casefold_mapping(self.code)
+    }
+
+    /// изменяется ли кодпоинт при NFKC_Casefold (CWKCF)
+    pub fn changes_when_nfkc_casefolded(&self, dataset: &Dataset) -> bool
+    {
+        dataset.changes_when_nfkc_casefolded(self.code)
+    }
+}
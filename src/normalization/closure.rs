@@ -0,0 +1,229 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::hangul;
+use crate::normalization::decompose;
+use crate::Dataset;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// канонические эквиваленты строки - все строки с той же NFD-декомпозицией, UTS #10, 6.1:
+// NFD-декомпозиция строки разбирается на кодпоинты, начиная с первого: первым может стать любой
+// кодпоинт, декомпозиция которого извлекается из последовательности так, что NFD-декомпозиция
+// его и остатка совпадает с исходной; остаток обрабатывается рекурсивно. одинаковые остатки
+// получаются разными путями, поэтому их эквиваленты запоминаются, а одинаковые кодпоинты в одной
+// позиции перебираются один раз. количество вариантов растёт факториально от длины
+// последовательности нестартеров с разными CCC, поэтому результат ограничен
+// MAX_CANONICAL_EQUIVALENTS вариантами
+
+/// максимальное количество канонических эквивалентов, возвращаемых для строки
+pub const MAX_CANONICAL_EQUIVALENTS: usize = 1024;
+
+/// каноническое замыкание: кодпоинты с канонической декомпозицией, в т.ч. слоги хангыль
+#[derive(Debug, Default, Clone)]
+pub struct CanonicalClosure
+{
+    /// NFD-декомпозиция -> кодпоинты с такой декомпозицией
+    by_decomposition: HashMap<Vec<u32>, Vec<u32>>,
+    /// первый кодпоинт NFD-декомпозиции -> кодпоинты
+    by_first: HashMap<u32, Vec<u32>>,
+}
+
+impl CanonicalClosure
+{
+    /// прекомпозированные кодпоинты с заданной NFD-декомпозицией
+    pub fn get(&self, decomposition: &[u32]) -> &[u32]
+    {
+        self.by_decomposition
+            .get(decomposition)
+            .map_or(&[], |codes| codes.as_slice())
+    }
+
+    /// прекомпозированные кодпоинты, NFD-декомпозиция которых начинается с заданного кодпоинта
+    pub fn starting_with(&self, code: u32) -> &[u32]
+    {
+        self.by_first
+            .get(&code)
+            .map_or(&[], |codes| codes.as_slice())
+    }
+
+    /// все пары (NFD-декомпозиция, прекомпозированные кодпоинты)
+    pub fn iter(&self) -> impl Iterator<Item = (&Vec<u32>, &Vec<u32>)>
+    {
+        self.by_decomposition.iter()
+    }
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// каноническое замыкание: NFD-декомпозиция -> прекомпозированные кодпоинты
+    pub static ref CANONICAL_CLOSURE: &'static CanonicalClosure =
+        UnicodeVersion::default().dataset().canonical_closure();
+}
+
+/// канонически эквивалентные варианты строки, не более MAX_CANONICAL_EQUIVALENTS
+#[cfg(feature = "embedded")]
+pub fn canonical_equivalents(text: &str) -> Vec<String>
+{
+    UnicodeVersion::default()
+        .dataset()
+        .canonical_equivalents(text)
+}
+
+impl Dataset
+{
+    /// канонически эквивалентные варианты строки, упорядоченные по кодпоинтам; если вариантов
+    /// больше MAX_CANONICAL_EQUIVALENTS - первые найденные MAX_CANONICAL_EQUIVALENTS из них
+    pub fn canonical_equivalents(&self, text: &str) -> Vec<String>
+    {
+        let codes: Vec<u32> = text.chars().map(u32::from).collect();
+
+        self.canonical_equivalents_codes(&codes)
+            .into_iter()
+            .map(|codes| codes.into_iter().filter_map(char::from_u32).collect())
+            .collect()
+    }
+
+    /// канонически эквивалентные варианты последовательности кодпоинтов, не более
+    /// MAX_CANONICAL_EQUIVALENTS
+    pub fn canonical_equivalents_codes(&self, codes: &[u32]) -> BTreeSet<Vec<u32>>
+    {
+        equivalents(
+            self,
+            &decompose(self, codes, true),
+            MAX_CANONICAL_EQUIVALENTS,
+            &mut HashMap::new(),
+        )
+    }
+}
+
+/// канонические эквиваленты NFD-последовательности, не более limit;
+/// cache - уже найденные эквиваленты остатков последовательности
+fn equivalents(
+    dataset: &Dataset,
+    nfd: &[u32],
+    limit: usize,
+    cache: &mut HashMap<Vec<u32>, BTreeSet<Vec<u32>>>,
+) -> BTreeSet<Vec<u32>>
+{
+    if let Some(result) = cache.get(nfd) {
+        return result.clone();
+    }
+
+    let mut result = BTreeSet::new();
+
+    if nfd.is_empty() {
+        result.insert(vec![]);
+        return result;
+    }
+
+    let closure = dataset.canonical_closure();
+    let ccc = |code: u32| dataset.unicode().get(&code).map_or(0, |c| c.ccc.u8());
+
+    // первым может оказаться кодпоинт, начинающийся с любого из ведущих нестартеров
+    // или с первого стартера
+    for (i, &code) in nfd.iter().enumerate() {
+        // одинаковые ведущие нестартеры дают одни и те же варианты
+        if nfd[.. i].contains(&code) {
+            continue;
+        }
+
+        let candidates = [code]
+            .into_iter()
+            .chain(closure.starting_with(code).iter().copied());
+
+        for candidate in candidates {
+            let rest = match extract(dataset, nfd, i, candidate) {
+                Some(rest) => rest,
+                None => continue,
+            };
+
+            let mut sequence = vec![candidate];
+            sequence.extend(&rest);
+
+            if decompose(dataset, &sequence, true) != nfd {
+                continue;
+            }
+
+            for mut tail in equivalents(dataset, &rest, limit, cache) {
+                if result.len() >= limit {
+                    break;
+                }
+
+                tail.insert(0, candidate);
+                result.insert(tail);
+            }
+
+            if result.len() >= limit {
+                break;
+            }
+        }
+
+        if ccc(code) == 0 || result.len() >= limit {
+            break;
+        }
+    }
+
+    cache.insert(nfd.to_vec(), result.clone());
+
+    result
+}
+
+/// извлечь декомпозицию кодпоинта из последовательности, начиная с позиции start;
+/// остаток последовательности или None, если декомпозиция не содержится в последовательности
+fn extract(dataset: &Dataset, nfd: &[u32], start: usize, code: u32) -> Option<Vec<u32>>
+{
+    let decomposition = decompose(dataset, &[code], true);
+
+    let mut rest = nfd.to_vec();
+    rest.remove(start);
+
+    let mut position = start;
+
+    for &code in &decomposition[1 ..] {
+        position += rest[position ..].iter().position(|&c| c == code)?;
+        rest.remove(position);
+    }
+
+    Some(rest)
+}
+
+/// каноническое замыкание набора данных
+pub(crate) fn canonical_closure(dataset: &Dataset) -> CanonicalClosure
+{
+    let mut closure = CanonicalClosure::default();
+
+    let decompositions = dataset
+        .nfd()
+        .iter()
+        .filter(|(_, decomposition)| !decomposition.is_empty())
+        .map(|(&code, decomposition)| (code, decomposition.iter().map(|c| c.code).collect()));
+
+    let syllables = (hangul::HANGUL_S_BASE .. hangul::HANGUL_S_BASE + hangul::HANGUL_S_COUNT)
+        .filter_map(|code| Some((code, hangul::decompose_hangul(code)?)));
+
+    for (code, decomposition) in decompositions.chain(syllables) {
+        let decomposition: Vec<u32> = decomposition;
+
+        closure
+            .by_first
+            .entry(decomposition[0])
+            .or_default()
+            .push(code);
+
+        closure
+            .by_decomposition
+            .entry(decomposition)
+            .or_default()
+            .push(code);
+    }
+
+    for codes in closure
+        .by_decomposition
+        .values_mut()
+        .chain(closure.by_first.values_mut())
+    {
+        codes.sort_unstable();
+    }
+
+    closure
+}
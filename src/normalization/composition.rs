@@ -1,23 +1,55 @@
 use std::collections::HashMap;
 
-use crate::normalization::precomposition::precompose;
 use crate::codepoint::Codepoint;
-use crate::UNICODE;
+use crate::Dataset;
 
-/// прекомпозиция NFC
-pub fn nfc() -> HashMap<u32, Vec<Codepoint>>
+/// прекомпозиция NFC / NFKC
+pub(crate) fn precompositions(dataset: &Dataset, canonical: bool) -> HashMap<u32, Vec<Codepoint>>
 {
-    UNICODE
-        .iter()
-        .map(|(code, codepoint)| (*code, precompose(codepoint.code, true)))
+    dataset
+        .unicode()
+        .keys()
+        .map(|code| (*code, dataset.precompose(*code, canonical)))
         .collect()
 }
 
-/// прекомпозиция NFKC
-pub fn nfkc() -> HashMap<u32, Vec<Codepoint>>
+/// каноническая композиция декомпозированной и упорядоченной последовательности, UAX #15, D117;
+/// contiguous - комбинировать только соседние кодпоинты (FCC)
+pub(crate) fn compose(dataset: &Dataset, codes: &[u32], contiguous: bool) -> Vec<u32>
 {
-    UNICODE
-        .iter()
-        .map(|(code, codepoint)| (*code, precompose(codepoint.code, false)))
-        .collect()
+    let ccc = |code: u32| dataset.unicode().get(&code).map_or(0, |c| c.ccc.u8());
+
+    let mut result: Vec<u32> = Vec::with_capacity(codes.len());
+    // позиция последнего стартера и CCC последнего кодпоинта после него
+    let mut starter: Option<usize> = None;
+    let mut last_ccc: Option<u8> = None;
+
+    for &code in codes {
+        let code_ccc = ccc(code);
+
+        if let Some(starter) = starter {
+            // кодпоинт заблокирован, если между ним и стартером есть стартер или кодпоинт
+            // с тем же или большим CCC, для FCC - любой кодпоинт
+            let blocked = last_ccc.is_some_and(|last| contiguous || last == 0 || last >= code_ccc);
+
+            if !blocked {
+                if let Some(combined) = dataset.combine(result[starter], code) {
+                    result[starter] = combined;
+                    continue;
+                }
+            }
+        }
+
+        match code_ccc {
+            0 => {
+                starter = Some(result.len());
+                last_ccc = None;
+            }
+            _ => last_ccc = Some(code_ccc),
+        }
+
+        result.push(code);
+    }
+
+    result
 }
@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+use crate::normalization::normalize;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, NormalizationForm, NormalizationTest};
+
+// проверка соответствия NormalizationTest.txt, инварианты из заголовка файла:
+//  * NFC:  c2 == NFC(c1) == NFC(c2) == NFC(c3), c4 == NFC(c4) == NFC(c5)
+//  * NFD:  c3 == NFD(c1) == NFD(c2) == NFD(c3), c5 == NFD(c4) == NFD(c5)
+//  * NFKC: c4 == NFKC(c1) == ... == NFKC(c5)
+//  * NFKD: c5 == NFKD(c1) == ... == NFKD(c5)
+//  * кодпоинты, отсутствующие в c1 Part 1, не изменяются ни одной из форм нормализации
+
+/// колонка теста нормализации
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationColumn
+{
+    /// исходная строка
+    C1,
+    /// NFC(c1)
+    C2,
+    /// NFD(c1)
+    C3,
+    /// NFKC(c1)
+    C4,
+    /// NFKD(c1)
+    C5,
+}
+
+/// строка теста, не прошедшая проверку
+#[derive(Clone)]
+pub struct NormalizationTestFailure
+{
+    /// тест; для кодпоинта, отсутствующего в Part 1, - все колонки равны кодпоинту, строка 0
+    pub test: NormalizationTest,
+    /// форма нормализации
+    pub form: NormalizationForm,
+    /// нормализуемая колонка
+    pub source: NormalizationColumn,
+    /// колонка с ожидаемым результатом
+    pub expected: NormalizationColumn,
+    /// полученный результат
+    pub actual: String,
+}
+
+/// результат проверки тестов нормализации
+#[derive(Debug, Clone)]
+pub struct NormalizationTestReport
+{
+    /// количество успешно пройденных строк
+    pub passed: usize,
+    /// количество проверенных кодпоинтов, отсутствующих в Part 1
+    pub unlisted: usize,
+    /// ошибки
+    pub failures: Vec<NormalizationTestFailure>,
+}
+
+/// инварианты: форма нормализации, ожидаемая колонка, нормализуемые колонки
+const INVARIANTS: [(
+    NormalizationForm,
+    NormalizationColumn,
+    &[NormalizationColumn],
+); 6] = {
+    use NormalizationColumn::*;
+    use NormalizationForm::*;
+
+    [
+        (Nfc, C2, &[C1, C2, C3]),
+        (Nfc, C4, &[C4, C5]),
+        (Nfd, C3, &[C1, C2, C3]),
+        (Nfd, C5, &[C4, C5]),
+        (Nfkc, C4, &[C1, C2, C3, C4, C5]),
+        (Nfkd, C5, &[C1, C2, C3, C4, C5]),
+    ]
+};
+
+/// проверить тесты нормализации набора данных по умолчанию
+#[cfg(feature = "embedded")]
+pub fn normalization_conformance() -> NormalizationTestReport
+{
+    UnicodeVersion::default()
+        .dataset()
+        .normalization_conformance()
+}
+
+impl Dataset
+{
+    /// проверить тесты нормализации (Part 0 - 3) и неизменность кодпоинтов, отсутствующих в Part 1
+    pub fn normalization_conformance(&self) -> NormalizationTestReport
+    {
+        let mut report = NormalizationTestReport {
+            passed: 0,
+            unlisted: 0,
+            failures: vec![],
+        };
+
+        let mut listed = HashSet::new();
+
+        for test in self.normalization_tests() {
+            if test.part.starts_with("@Part1") {
+                listed.extend(test.c1.chars());
+            }
+
+            let failures = report.failures.len();
+
+            for (form, expected, sources) in INVARIANTS {
+                for &source in sources {
+                    self.check_normalization(test, form, source, expected, &mut report.failures);
+                }
+            }
+
+            if report.failures.len() == failures {
+                report.passed += 1;
+            }
+        }
+
+        for c in (0 ..= 0x10FFFF).filter_map(char::from_u32) {
+            if listed.contains(&c) {
+                continue;
+            }
+
+            let test = NormalizationTest {
+                part: "@Part1".to_owned(),
+                description: "кодпоинт отсутствует в Part 1".to_owned(),
+                line: 0,
+                c1: c.to_string(),
+                c2: c.to_string(),
+                c3: c.to_string(),
+                c4: c.to_string(),
+                c5: c.to_string(),
+            };
+
+            for form in NormalizationForm::ALL {
+                self.check_normalization(
+                    &test,
+                    form,
+                    NormalizationColumn::C1,
+                    NormalizationColumn::C1,
+                    &mut report.failures,
+                );
+            }
+
+            report.unlisted += 1;
+        }
+
+        report
+    }
+
+    /// проверить один инвариант теста
+    fn check_normalization(
+        &self,
+        test: &NormalizationTest,
+        form: NormalizationForm,
+        source: NormalizationColumn,
+        expected: NormalizationColumn,
+        failures: &mut Vec<NormalizationTestFailure>,
+    )
+    {
+        let codes: Vec<u32> = source.of(test).chars().map(u32::from).collect();
+
+        let actual: String = normalize(self, &codes, form)
+            .into_iter()
+            .filter_map(char::from_u32)
+            .collect();
+
+        if actual != expected.of(test) {
+            failures.push(NormalizationTestFailure {
+                test: test.clone(),
+                form,
+                source,
+                expected,
+                actual,
+            });
+        }
+    }
+}
+
+impl NormalizationColumn
+{
+    /// значение колонки теста
+    pub fn of<'a>(&self, test: &'a NormalizationTest) -> &'a str
+    {
+        match self {
+            Self::C1 => &test.c1,
+            Self::C2 => &test.c2,
+            Self::C3 => &test.c3,
+            Self::C4 => &test.c4,
+            Self::C5 => &test.c5,
+        }
+    }
+}
+
+impl NormalizationTestReport
+{
+    /// все строки прошли проверку
+    pub fn is_ok(&self) -> bool
+    {
+        self.failures.is_empty()
+    }
+}
+
+impl core::fmt::Debug for NormalizationTestFailure
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let actual: String = self
+            .actual
+            .chars()
+            .map(|c| format!("{:04X} ", u32::from(c)))
+            .collect();
+
+        write!(
+            f,
+            "{:?}\n{:?} != {}({:?}): {}\n",
+            self.test, self.expected, self.form, self.source, actual
+        )
+    }
+}
+
+impl core::fmt::Display for NormalizationTestReport
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(
+            f,
+            "NormalizationTest.txt: пройдено {}, кодпоинтов вне Part 1: {}, ошибок {}",
+            self.passed,
+            self.unlisted,
+            self.failures.len()
+        )?;
+
+        for failure in &self.failures {
+            write!(f, "{:?}", failure)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,28 +1,21 @@
 use std::collections::HashMap;
 
 use crate::codepoint::Codepoint;
-use crate::UNICODE;
+use crate::hangul;
+use crate::Dataset;
 
-/// полная декомпозиция NFD
-pub fn nfd() -> HashMap<u32, Vec<Codepoint>>
+/// полная декомпозиция NFD / NFKD
+pub(crate) fn decomposition(dataset: &Dataset, canonical: bool) -> HashMap<u32, Vec<Codepoint>>
 {
-    UNICODE
+    dataset
+        .unicode()
         .iter()
-        .map(|(code, codepoint)| (*code, decompose_entry(codepoint, true)))
-        .collect()
-}
-
-/// полная декомпозиция NFKD
-pub fn nfkd() -> HashMap<u32, Vec<Codepoint>>
-{
-    UNICODE
-        .iter()
-        .map(|(code, codepoint)| (*code, decompose_entry(codepoint, false)))
+        .map(|(code, codepoint)| (*code, decompose_entry(dataset, codepoint, canonical)))
         .collect()
 }
 
 /// построить развернутую декомпозицию символа
-fn decompose_entry(codepoint: &Codepoint, canonical: bool) -> Vec<Codepoint>
+fn decompose_entry(dataset: &Dataset, codepoint: &Codepoint, canonical: bool) -> Vec<Codepoint>
 {
     let mut result: Vec<Codepoint> = vec![];
 
@@ -33,10 +26,10 @@ fn decompose_entry(codepoint: &Codepoint, canonical: bool) -> Vec<Codepoint>
 
     // проходим по всем элементам декомпозиции
     for code in codepoint.decomposition.iter() {
-        let codepoint = &UNICODE[code];
+        let codepoint = &dataset.unicode()[code];
 
         // получаем декомпозицию элемента (если она есть)
-        let codepoint_decomposition = decompose_entry(codepoint, canonical);
+        let codepoint_decomposition = decompose_entry(dataset, codepoint, canonical);
 
         match codepoint_decomposition.is_empty() {
             true => {
@@ -50,3 +43,42 @@ fn decompose_entry(codepoint: &Codepoint, canonical: bool) -> Vec<Codepoint>
 
     result
 }
+
+/// декомпозиция NFD / NFKD последовательности кодов с каноническим упорядочиванием
+pub(crate) fn decompose(dataset: &Dataset, codes: &[u32], canonical: bool) -> Vec<u32>
+{
+    let table = match canonical {
+        true => dataset.nfd(),
+        false => dataset.nfkd(),
+    };
+
+    let mut result = Vec::with_capacity(codes.len());
+
+    for &code in codes {
+        if let Some(jamo) = hangul::decompose_hangul(code) {
+            result.extend(jamo);
+            continue;
+        }
+
+        match table.get(&code) {
+            Some(decomposition) if !decomposition.is_empty() => {
+                result.extend(decomposition.iter().map(|c| c.code))
+            }
+            _ => result.push(code),
+        }
+    }
+
+    canonical_order(dataset, &mut result);
+
+    result
+}
+
+/// каноническое упорядочивание: нестартеры между стартерами сортируются по CCC (сортировка стабильная)
+pub(crate) fn canonical_order(dataset: &Dataset, codes: &mut [u32])
+{
+    let ccc = |code: &u32| dataset.unicode().get(code).map_or(0, |c| c.ccc.u8());
+
+    for run in codes.split_mut(|code| ccc(code) == 0) {
+        run.sort_by_key(ccc);
+    }
+}
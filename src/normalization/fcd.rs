@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use crate::Dataset;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// FCD (UTN #5): строка, каноническое упорядочивание NFD-декомпозиции которой ничего не меняет -
+// для каждой пары соседних кодпоинтов CCC первого кодпоинта декомпозиции второго (lead)
+// равен 0 или не меньше CCC последнего кодпоинта декомпозиции первого (trail)
+
+/// CCC первого и последнего кодпоинта канонической декомпозиции
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FcdClasses
+{
+    /// CCC первого кодпоинта декомпозиции
+    pub lead: u8,
+    /// CCC последнего кодпоинта декомпозиции
+    pub trail: u8,
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// lead / trail CCC кодпоинтов, у которых хотя бы одно из значений не равно 0
+    pub static ref FCD: &'static HashMap<u32, FcdClasses> =
+        UnicodeVersion::default().dataset().fcd();
+}
+
+/// находится ли строка в форме FCD
+#[cfg(feature = "embedded")]
+pub fn is_fcd(text: &str) -> bool
+{
+    UnicodeVersion::default().dataset().is_fcd(text)
+}
+
+impl Dataset
+{
+    /// lead / trail CCC канонической декомпозиции кодпоинта
+    pub fn fcd_classes(&self, code: u32) -> FcdClasses
+    {
+        self.fcd().get(&code).copied().unwrap_or_default()
+    }
+
+    /// находится ли строка в форме FCD
+    pub fn is_fcd(&self, text: &str) -> bool
+    {
+        let mut last_trail = 0;
+
+        for c in text.chars() {
+            let classes = self.fcd_classes(u32::from(c));
+
+            if classes.lead != 0 && classes.lead < last_trail {
+                return false;
+            }
+
+            last_trail = classes.trail;
+        }
+
+        true
+    }
+}
+
+/// lead / trail CCC кодпоинтов по таблице NFD; слоги хангыль раскладываются на стартеры
+pub(crate) fn fcd(dataset: &Dataset) -> HashMap<u32, FcdClasses>
+{
+    let mut table = HashMap::new();
+
+    for (code, codepoint) in dataset.unicode() {
+        let classes = match dataset.nfd().get(code) {
+            Some(decomposition) if !decomposition.is_empty() => FcdClasses {
+                lead: decomposition[0].ccc.u8(),
+                trail: decomposition[decomposition.len() - 1].ccc.u8(),
+            },
+            _ => FcdClasses {
+                lead: codepoint.ccc.u8(),
+                trail: codepoint.ccc.u8(),
+            },
+        };
+
+        if classes != FcdClasses::default() {
+            table.insert(*code, classes);
+        }
+    }
+
+    table
+}
@@ -1,12 +1,37 @@
+#[cfg(feature = "embedded")]
 use std::collections::HashMap;
 
+#[cfg(feature = "embedded")]
 use crate::codepoint::Codepoint;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
 
+mod casefold;
+mod closure;
 mod composition;
+mod conformance;
 mod decomposition;
+mod fcd;
+mod normalizer;
 mod precomposition;
-
+mod quick_check;
+mod stream;
+
+#[cfg(feature = "embedded")]
+pub use casefold::*;
+pub(crate) use closure::canonical_closure;
+pub use closure::*;
+pub(crate) use composition::{compose, precompositions};
+pub use conformance::*;
+pub(crate) use decomposition::{canonical_order, decompose, decomposition};
+pub(crate) use fcd::fcd;
+pub use fcd::*;
+pub(crate) use normalizer::normalize;
+pub use normalizer::*;
 pub use precomposition::*;
+#[cfg(feature = "embedded")]
+pub use quick_check::*;
+pub use stream::*;
 
 // в файле UnicodeData.txt хранится декомпозиция в сжатом виде, т.е. элементы декомпозиции
 // могут также иметь свою декомпозицию.
@@ -14,16 +39,17 @@ pub use precomposition::*;
 //  * NFC, NFKC: получаем "пересобранную" версию - если кодпоинт может быть скомбинирован единственно возможным
 //    способом - зачем в таком случае сначала делать декомпозицию, а потом собирать обратно? (пример - синглтоны)
 
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// таблица декомпозиций NFD
-    pub static ref NFD: HashMap<u32, Vec<Codepoint>> = decomposition::nfd();
+    pub static ref NFD: &'static HashMap<u32, Vec<Codepoint>> = UnicodeVersion::default().dataset().nfd();
 
     /// таблица декомпозиций NFKD
-    pub static ref NFKD: HashMap<u32, Vec<Codepoint>> = decomposition::nfkd();
+    pub static ref NFKD: &'static HashMap<u32, Vec<Codepoint>> = UnicodeVersion::default().dataset().nfkd();
 
     /// таблица композиций NFC
-    pub static ref NFC: HashMap<u32, Vec<Codepoint>> = composition::nfc();
+    pub static ref NFC: &'static HashMap<u32, Vec<Codepoint>> = UnicodeVersion::default().dataset().nfc();
 
     /// таблица композиций NFKC
-    pub static ref NFKC: HashMap<u32, Vec<Codepoint>> = composition::nfkc();
+    pub static ref NFKC: &'static HashMap<u32, Vec<Codepoint>> = UnicodeVersion::default().dataset().nfkc();
 }
@@ -0,0 +1,246 @@
+use crate::normalization::{canonical_order, compose, decompose, NonStarters, NormalizedChars};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{hangul, Dataset};
+
+// нормализация строк, UAX #15:
+//  * декомпозиция по таблицам NFD / NFKD и алгоритмическая декомпозиция слогов хангыль
+//  * каноническое упорядочивание нестартеров по CCC
+//  * каноническая композиция с учётом блокировки (NFC, NFKC)
+// строка обрабатывается сегментами - от стартера до стартера; для NFC / NFKC сегмент продолжается,
+// если стартер может быть скомбинирован с предыдущим (уже скомбинированным) кодпоинтом
+
+/// форма нормализации, UAX #15
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm
+{
+    /// каноническая декомпозиция
+    Nfd,
+    /// декомпозиция совместимости
+    Nfkd,
+    /// каноническая декомпозиция и каноническая композиция
+    Nfc,
+    /// декомпозиция совместимости и каноническая композиция
+    Nfkc,
+    /// каноническая декомпозиция и композиция только соседних кодпоинтов (FCC, UTN #5)
+    Fcc,
+}
+
+impl NormalizationForm
+{
+    /// формы нормализации UAX #15
+    pub const ALL: [Self; 4] = [Self::Nfd, Self::Nfkd, Self::Nfc, Self::Nfkc];
+
+    /// название формы, например "NFC"
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::Nfd => "NFD",
+            Self::Nfkd => "NFKD",
+            Self::Nfc => "NFC",
+            Self::Nfkc => "NFKC",
+            Self::Fcc => "FCC",
+        }
+    }
+
+    /// используется каноническая декомпозиция
+    pub fn is_canonical(&self) -> bool
+    {
+        matches!(self, Self::Nfd | Self::Nfc | Self::Fcc)
+    }
+
+    /// после декомпозиции выполняется композиция
+    pub fn is_composed(&self) -> bool
+    {
+        matches!(self, Self::Nfc | Self::Nfkc | Self::Fcc)
+    }
+
+    /// комбинируются только соседние кодпоинты
+    pub fn is_contiguous(&self) -> bool
+    {
+        matches!(self, Self::Fcc)
+    }
+}
+
+impl core::fmt::Display for NormalizationForm
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.write_str(self.as_str())
+    }
+}
+
+/// нормализация строк в одной из форм
+#[derive(Debug, Clone, Copy)]
+pub struct Normalizer<'a>
+{
+    dataset: &'a Dataset,
+    form: NormalizationForm,
+}
+
+/// NFD-форма строки
+#[cfg(feature = "embedded")]
+pub fn nfd(text: &str) -> String
+{
+    Normalizer::new(UnicodeVersion::default().dataset(), NormalizationForm::Nfd).normalize(text)
+}
+
+/// NFKD-форма строки
+#[cfg(feature = "embedded")]
+pub fn nfkd(text: &str) -> String
+{
+    Normalizer::new(UnicodeVersion::default().dataset(), NormalizationForm::Nfkd).normalize(text)
+}
+
+/// NFC-форма строки
+#[cfg(feature = "embedded")]
+pub fn nfc(text: &str) -> String
+{
+    Normalizer::new(UnicodeVersion::default().dataset(), NormalizationForm::Nfc).normalize(text)
+}
+
+/// NFKC-форма строки
+#[cfg(feature = "embedded")]
+pub fn nfkc(text: &str) -> String
+{
+    Normalizer::new(UnicodeVersion::default().dataset(), NormalizationForm::Nfkc).normalize(text)
+}
+
+/// FCC-форма строки
+#[cfg(feature = "embedded")]
+pub fn fcc(text: &str) -> String
+{
+    Normalizer::new(UnicodeVersion::default().dataset(), NormalizationForm::Fcc).normalize(text)
+}
+
+impl<'a> Normalizer<'a>
+{
+    /// нормализация по таблицам набора данных
+    pub fn new(dataset: &'a Dataset, form: NormalizationForm) -> Self
+    {
+        Self { dataset, form }
+    }
+
+    /// форма нормализации
+    pub fn form(&self) -> NormalizationForm
+    {
+        self.form
+    }
+
+    /// нормализовать строку
+    pub fn normalize(&self, text: &str) -> String
+    {
+        let mut result = String::with_capacity(text.len());
+
+        // запись в String не возвращает ошибок
+        let _ = self.normalize_to(text, &mut result);
+
+        result
+    }
+
+    /// нормализовать строку, записывая результат посегментно
+    pub fn normalize_to<W: core::fmt::Write>(&self, text: &str, out: &mut W) -> core::fmt::Result
+    {
+        let mut segment = vec![];
+
+        for c in text.chars() {
+            for code in self.decompose(c) {
+                if !segment.is_empty() && self.is_segment_start(&mut segment, code) {
+                    self.flush(&mut segment, out)?;
+                }
+
+                segment.push(code);
+            }
+        }
+
+        self.flush(&mut segment, out)
+    }
+
+    /// нормализовать последовательность символов - итератор, буферизующий один сегмент
+    pub fn normalize_chars<I>(&self, chars: I) -> NormalizedChars<'a, I::IntoIter>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        NormalizedChars::new(*self, chars.into_iter())
+    }
+
+    /// декомпозиция символа в форме нормализации
+    pub(crate) fn decompose(&self, c: char) -> Vec<u32>
+    {
+        decompose(self.dataset, &[u32::from(c)], self.form.is_canonical())
+    }
+
+    /// нестартеры в начале и в конце NFKD-декомпозиции символа
+    pub(crate) fn nonstarters(&self, c: char) -> NonStarters
+    {
+        self.dataset.nonstarters(u32::from(c))
+    }
+
+    /// кодпоинт не является стартером
+    pub(crate) fn is_nonstarter(&self, code: u32) -> bool
+    {
+        self.dataset
+            .unicode()
+            .get(&code)
+            .is_some_and(|c| c.is_nonstarter())
+    }
+
+    /// кодпоинт начинает новый сегмент - стартер, не комбинируемый с сегментом; стартер может
+    /// скомбинироваться только с непосредственно предшествующим ему стартером, поэтому в NFC / NFKC
+    /// сегмент перед проверкой комбинируется - иначе буфер растёт без ограничений на
+    /// последовательностях вроде U+1100 U+1161 U+1161 ...
+    pub(crate) fn is_segment_start(&self, segment: &mut Vec<u32>, code: u32) -> bool
+    {
+        if self.is_nonstarter(code) {
+            return false;
+        }
+
+        if !self.form.is_composed()
+            || !(self.dataset.combines_backwards().contains_key(&code)
+                || hangul::is_composable_hangul_jamo(code))
+        {
+            return true;
+        }
+
+        self.finish_segment(segment);
+
+        match segment.last() {
+            Some(&last) => self.is_nonstarter(last) || self.dataset.combine(last, code).is_none(),
+            None => false,
+        }
+    }
+
+    /// упорядочить и, при необходимости, скомбинировать сегмент
+    pub(crate) fn finish_segment(&self, segment: &mut Vec<u32>)
+    {
+        canonical_order(self.dataset, segment);
+
+        if self.form.is_composed() {
+            *segment = compose(self.dataset, segment, self.form.is_contiguous());
+        }
+    }
+
+    /// обработать и записать сегмент
+    fn flush<W: core::fmt::Write>(&self, segment: &mut Vec<u32>, out: &mut W) -> core::fmt::Result
+    {
+        self.finish_segment(segment);
+
+        for code in segment.drain(..) {
+            // декомпозиция и композиция строки дают только скалярные значения
+            out.write_char(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// нормализация последовательности кодов: декомпозиция, каноническое упорядочивание, композиция
+pub(crate) fn normalize(dataset: &Dataset, codes: &[u32], form: NormalizationForm) -> Vec<u32>
+{
+    let decomposed = decompose(dataset, codes, form.is_canonical());
+
+    match form.is_composed() {
+        true => compose(dataset, &decomposed, form.is_contiguous()),
+        false => decomposed,
+    }
+}
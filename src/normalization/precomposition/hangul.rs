@@ -41,7 +41,7 @@ pub fn compose_hangul(first: u32, second: u32) -> Option<u32>
     let lv = first.wrapping_sub(HANGUL_S_BASE);
 
     // первый кодпоинт - слог хангыль LV
-    if lv < HANGUL_S_COUNT && lv % HANGUL_T_BLOCK_SIZE == 0 {
+    if lv < HANGUL_S_COUNT && lv.is_multiple_of(HANGUL_T_BLOCK_SIZE) {
         let t = second.wrapping_sub(HANGUL_T_BASE);
 
         // второй кодпоинт - завершающая согласная
@@ -51,7 +51,26 @@ pub fn compose_hangul(first: u32, second: u32) -> Option<u32>
         }
     }
 
-    return None;
+    None
+}
+
+// декомпозиция слога хангыль на чамо L, V и, если есть, T
+pub fn decompose_hangul(code: u32) -> Option<Vec<u32>>
+{
+    let s = code.wrapping_sub(HANGUL_S_BASE);
+
+    if s >= HANGUL_S_COUNT {
+        return None;
+    }
+
+    let l = HANGUL_L_BASE + s / HANGUL_N_COUNT;
+    let v = HANGUL_V_BASE + (s % HANGUL_N_COUNT) / HANGUL_T_BLOCK_SIZE;
+    let t = s % HANGUL_T_BLOCK_SIZE;
+
+    match t {
+        0 => Some(vec![l, v]),
+        _ => Some(vec![l, v, HANGUL_T_BASE + t - 1]),
+    }
 }
 
 // является ли кодпоинт слогом хангыль?
@@ -69,7 +88,7 @@ pub fn is_composable_hangul(code: u32) -> bool
     let lv = code.wrapping_sub(HANGUL_S_BASE);
 
     // кодпоинт является ведущей согласной чамо / первый кодпоинт - слог хангыль LV
-    (l < HANGUL_L_COUNT) || (lv < HANGUL_S_COUNT && lv % HANGUL_T_BLOCK_SIZE == 0)
+    (l < HANGUL_L_COUNT) || (lv < HANGUL_S_COUNT && lv.is_multiple_of(HANGUL_T_BLOCK_SIZE))
 }
 
 // является ли кодпоинт гласной или завершающей согласной чамо?
@@ -1,12 +1,16 @@
 use std::collections::HashMap;
 
 use crate::codepoint::Codepoint;
-use crate::COMPOSITION_EXCLUSIONS;
-use crate::UNICODE;
-use crate::{NFD, NFKD};
+use crate::Dataset;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
 
-pub use pairs::COMPOSITION_PAIRS;
+#[cfg(feature = "embedded")]
 pub use pairs::COMBINES_BACKWARDS;
+#[cfg(feature = "embedded")]
+pub use pairs::COMPOSITION_PAIRS;
+
+pub(crate) use pairs::{combines_backwards, pairs};
 
 /// композиция хангыль
 pub mod hangul;
@@ -15,11 +19,48 @@ pub mod hangul;
 pub mod pairs;
 
 /// прекомпозиция - в некоторых случаях делать полную декомпозицию, а затем каноническую композицию - избыточно
+#[cfg(feature = "embedded")]
 pub fn precompose(code: u32, canonical: bool) -> Vec<Codepoint>
+{
+    UnicodeVersion::default()
+        .dataset()
+        .precompose(code, canonical)
+}
+
+/// комбинирование двух кодпоинтов
+#[cfg(feature = "embedded")]
+pub fn combine(a: u32, b: u32) -> Option<u32>
+{
+    UnicodeVersion::default().dataset().combine(a, b)
+}
+
+impl Dataset
+{
+    /// прекомпозиция - в некоторых случаях делать полную декомпозицию, а затем каноническую композицию - избыточно
+    pub fn precompose(&self, code: u32, canonical: bool) -> Vec<Codepoint>
+    {
+        precompose_entry(self, code, canonical)
+    }
+
+    /// комбинирование двух кодпоинтов
+    pub fn combine(&self, a: u32, b: u32) -> Option<u32>
+    {
+        match hangul::compose_hangul(a, b) {
+            Some(c) => Some(c),
+            None => self
+                .composition_pairs()
+                .get(&a)
+                .and_then(|pairs| pairs.get(&b))
+                .map(|c| c.code),
+        }
+    }
+}
+
+fn precompose_entry(dataset: &Dataset, code: u32, canonical: bool) -> Vec<Codepoint>
 {
     let decomposition_map: &HashMap<u32, Vec<Codepoint>> = match canonical {
-        true => &NFD,
-        false => &NFKD,
+        true => dataset.nfd(),
+        false => dataset.nfkd(),
     };
 
     let mut decomposition = match decomposition_map.get(&code) {
@@ -64,7 +105,7 @@ pub fn precompose(code: u32, canonical: bool) -> Vec<Codepoint>
         // однако есть частный случай - все нестартеры подпоследовательности имеют минимальный CCC
 
         if (end + 1 == decomposition.len()) && decomposition[end].is_nonstarter() {
-            let tail = get_min_variant(&decomposition[start ..]);
+            let tail = get_min_variant(dataset, &decomposition[start ..]);
             decomposition = decomposition[.. start].to_vec();
             decomposition.extend(tail);
 
@@ -90,8 +131,8 @@ pub fn precompose(code: u32, canonical: bool) -> Vec<Codepoint>
                 move_to_next = 0;
             }
 
-            match combine(first.code, codepoint.code) {
-                Some(combined) => first = UNICODE[&combined].clone(),
+            match dataset.combine(first.code, codepoint.code) {
+                Some(combined) => first = dataset.unicode()[&combined].clone(),
                 None => {
                     last_starter = first.clone();
                     last_ccc = codepoint.ccc.u8();
@@ -117,26 +158,8 @@ pub fn precompose(code: u32, canonical: bool) -> Vec<Codepoint>
     }
 }
 
-pub fn combine(a: u32, b: u32) -> Option<u32>
-{
-    match hangul::compose_hangul(a, b) {
-        Some(c) => Some(c),
-        None => {
-            let pairs = match COMPOSITION_PAIRS.get(&a) {
-                Some(pairs) => pairs.clone(),
-                None => HashMap::new(),
-            };
-
-            match pairs.get(&b) {
-                Some(c) => Some(c.code),
-                None => None,
-            }
-        }
-    }
-}
-
 /// последовательность из стартера и нескольких нестартеров. можно-ли упростить?
-fn get_min_variant(decomposition: &[Codepoint]) -> Vec<Codepoint>
+fn get_min_variant(dataset: &Dataset, decomposition: &[Codepoint]) -> Vec<Codepoint>
 {
     assert!(decomposition[0].is_starter());
     assert!(decomposition[1 ..].iter().all(|c| c.is_nonstarter()));
@@ -147,18 +170,18 @@ fn get_min_variant(decomposition: &[Codepoint]) -> Vec<Codepoint>
     let mut pos = 0;
 
     for nonstarter in nonstarters.iter() {
-        if COMPOSITION_EXCLUSIONS.contains(&first.code) {
+        if dataset.is_composition_exclusion(first.code) {
             break;
         }
 
-        match get_min_ccc_combined(first.code) {
+        match get_min_ccc_combined(dataset, first.code) {
             Some(min_ccc) if nonstarter.ccc.u8() == min_ccc => (),
             _ => break,
         };
 
-        match combine(first.code, nonstarter.code) {
+        match dataset.combine(first.code, nonstarter.code) {
             Some(combined) => {
-                first = UNICODE[&combined].clone();
+                first = dataset.unicode()[&combined].clone();
             }
             None => {
                 break;
@@ -175,14 +198,14 @@ fn get_min_variant(decomposition: &[Codepoint]) -> Vec<Codepoint>
 }
 
 /// минимальный ccc следующего кодпоинта, с которым можно скомбинировать текущий
-fn get_min_ccc_combined(code: u32) -> Option<u8>
+fn get_min_ccc_combined(dataset: &Dataset, code: u32) -> Option<u8>
 {
     let mut min_ccc = 255;
 
-    match COMPOSITION_PAIRS.get(&code) {
+    match dataset.composition_pairs().get(&code) {
         Some(pairs) => {
             for c in pairs.keys() {
-                let ccc = u8::from(UNICODE[c].ccc);
+                let ccc = u8::from(dataset.unicode()[c].ccc);
 
                 if ccc == 0 {
                     continue;
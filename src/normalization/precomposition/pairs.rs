@@ -1,27 +1,32 @@
 use std::collections::HashMap;
 
 use crate::codepoint::Codepoint;
-use crate::COMPOSITION_EXCLUSIONS;
-use crate::UNICODE;
+use crate::Dataset;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
 
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// комбинируемые кодпоинты
-    pub static ref COMPOSITION_PAIRS: HashMap<u32, HashMap<u32, Codepoint>> = pairs();
+    pub static ref COMPOSITION_PAIRS: &'static HashMap<u32, HashMap<u32, Codepoint>> =
+        UnicodeVersion::default().dataset().composition_pairs();
     /// кодпоинты, комбинируемые с предыдущими
-    pub static ref COMBINES_BACKWARDS: HashMap<u32, HashMap<u32, Codepoint>> = combines_backwards();
+    pub static ref COMBINES_BACKWARDS: &'static HashMap<u32, HashMap<u32, Codepoint>> =
+        UnicodeVersion::default().dataset().combines_backwards();
 }
 
 /// хешмап пар для композиции
-fn pairs() -> HashMap<u32, HashMap<u32, Codepoint>>
+pub(crate) fn pairs(dataset: &Dataset) -> HashMap<u32, HashMap<u32, Codepoint>>
 {
     let mut map: HashMap<u32, HashMap<u32, Codepoint>> = HashMap::new();
+    let unicode = dataset.unicode();
 
-    for codepoint in UNICODE.values() {
+    for codepoint in unicode.values() {
         if codepoint.decomposition.len() != 2 || codepoint.decomposition_tag.is_some() {
             continue;
         }
 
-        if COMPOSITION_EXCLUSIONS.contains(&codepoint.code) {
+        if dataset.is_composition_exclusion(codepoint.code) {
             continue;
         }
 
@@ -29,8 +34,8 @@ fn pairs() -> HashMap<u32, HashMap<u32, Codepoint>>
             continue;
         }
 
-        let c0 = &UNICODE[&codepoint.decomposition[0]];
-        let c1 = &UNICODE[&codepoint.decomposition[1]];
+        let c0 = &unicode[&codepoint.decomposition[0]];
+        let c1 = &unicode[&codepoint.decomposition[1]];
 
         if c0.is_nonstarter() && c1.is_nonstarter() {
             continue;
@@ -51,30 +56,24 @@ fn pairs() -> HashMap<u32, HashMap<u32, Codepoint>>
 }
 
 /// может ли быть скомбинирован с каким-либо предстоящим кодпоинтом?
-fn combines_backwards() -> HashMap<u32, HashMap<u32, Codepoint>>
+pub(crate) fn combines_backwards(dataset: &Dataset) -> HashMap<u32, HashMap<u32, Codepoint>>
 {
     let mut map: HashMap<u32, HashMap<u32, Codepoint>> = HashMap::new();
 
-    for entry in UNICODE.values() {
+    for entry in dataset.unicode().values() {
         // декомпозиция отсутствует, синглтон или не является канонической
         if (entry.decomposition.len() != 2) || entry.decomposition_tag.is_some() {
             continue;
         }
 
-        if COMPOSITION_EXCLUSIONS.contains(&entry.code) {
+        if dataset.is_composition_exclusion(entry.code) {
             continue;
         }
 
         let c0 = entry.decomposition[0];
         let c1 = entry.decomposition[1];
 
-        if !map.contains_key(&c1) {
-            map.insert(c1, HashMap::new());
-        }
-
-        map.entry(c1)
-            .or_insert(HashMap::new())
-            .insert(c0, entry.clone());
+        map.entry(c1).or_default().insert(c0, entry.clone());
     }
 
     map
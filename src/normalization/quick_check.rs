@@ -0,0 +1,72 @@
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, NormalizationForm, QuickCheck, QuickCheckTable};
+
+/// значение быстрой проверки кодпоинта
+#[cfg(feature = "embedded")]
+pub fn quick_check(code: u32, form: NormalizationForm) -> QuickCheck
+{
+    UnicodeVersion::default().dataset().quick_check(code, form)
+}
+
+/// быстрая проверка, находится ли строка в форме нормализации
+#[cfg(feature = "embedded")]
+pub fn is_normalized_quick(text: &str, form: NormalizationForm) -> QuickCheck
+{
+    UnicodeVersion::default()
+        .dataset()
+        .is_normalized_quick(text, form)
+}
+
+impl Dataset
+{
+    /// таблица быстрых проверок формы нормализации; для FCC - таблица NFC
+    pub fn quick_check_table(&self, form: NormalizationForm) -> &QuickCheckTable
+    {
+        match form {
+            NormalizationForm::Nfd => self.qc_nfd(),
+            NormalizationForm::Nfkd => self.qc_nfkd(),
+            NormalizationForm::Nfc | NormalizationForm::Fcc => self.qc_nfc(),
+            NormalizationForm::Nfkc => self.qc_nfkc(),
+        }
+    }
+
+    /// значение быстрой проверки кодпоинта
+    pub fn quick_check(&self, code: u32, form: NormalizationForm) -> QuickCheck
+    {
+        self.quick_check_table(form).get(code)
+    }
+
+    /// быстрая проверка, находится ли строка в форме нормализации, UAX #15, 9.1:
+    /// No - если у кодпоинта NF*_QC=No или нарушен канонический порядок нестартеров;
+    /// строка в форме FCC - в форме NFC и FCD
+    pub fn is_normalized_quick(&self, text: &str, form: NormalizationForm) -> QuickCheck
+    {
+        if form == NormalizationForm::Fcc && !self.is_fcd(text) {
+            return QuickCheck::No;
+        }
+
+        let table = self.quick_check_table(form);
+        let mut last_ccc = 0;
+        let mut result = QuickCheck::Yes;
+
+        for c in text.chars() {
+            let code = u32::from(c);
+            let ccc = self.unicode().get(&code).map_or(0, |c| c.ccc.u8());
+
+            if last_ccc > ccc && ccc != 0 {
+                return QuickCheck::No;
+            }
+
+            match table.get(code) {
+                QuickCheck::Yes => (),
+                QuickCheck::No => return QuickCheck::No,
+                QuickCheck::Maybe => result = QuickCheck::Maybe,
+            }
+
+            last_ccc = ccc;
+        }
+
+        result
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, Normalizer};
+
+// Stream-Safe Text Format, UAX #15, 13: в тексте нет последовательностей из более чем 30
+// нестартеров подряд (с учётом NFKD-декомпозиции); при преобразовании перед кодпоинтом,
+// превышающим предел, вставляется U+034F COMBINING GRAPHEME JOINER.
+//
+// потоковая нормализация: в буфере - только текущий сегмент (от стартера до стартера),
+// входной текст приводится к Stream-Safe Text Format, а стартер продолжает сегмент, только если
+// комбинируется с его последним кодпоинтом, поэтому длина сегмента ограничена
+
+/// максимальное количество нестартеров подряд в Stream-Safe Text Format
+pub const MAX_NONSTARTERS: usize = 30;
+
+/// COMBINING GRAPHEME JOINER - стартер, разделяющий длинные последовательности нестартеров
+pub const CGJ: char = '\u{034F}';
+
+/// количество нестартеров в начале и в конце NFKD-декомпозиции кодпоинта
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonStarters
+{
+    /// нестартеры в начале декомпозиции
+    pub leading: usize,
+    /// нестартеры в конце декомпозиции
+    pub trailing: usize,
+    /// декомпозиция состоит только из нестартеров
+    pub all: bool,
+}
+
+/// счётчик нестартеров подряд, UAX #15, 13
+#[derive(Debug, Default, Clone, Copy)]
+struct NonStartersCounter(usize);
+
+impl NonStartersCounter
+{
+    /// учесть кодпоинт; false - перед кодпоинтом необходимо вставить CGJ
+    fn push(&mut self, nonstarters: NonStarters) -> bool
+    {
+        let fits = self.0 + nonstarters.leading <= MAX_NONSTARTERS;
+
+        if !fits {
+            self.0 = 0;
+        }
+
+        self.0 = match nonstarters.all {
+            true => self.0 + nonstarters.leading,
+            false => nonstarters.trailing,
+        };
+
+        fits
+    }
+}
+
+/// находится ли текст в Stream-Safe Text Format
+#[cfg(feature = "embedded")]
+pub fn is_stream_safe(text: &str) -> bool
+{
+    UnicodeVersion::default().dataset().is_stream_safe(text)
+}
+
+/// привести текст к Stream-Safe Text Format
+#[cfg(feature = "embedded")]
+pub fn stream_safe(text: &str) -> String
+{
+    UnicodeVersion::default().dataset().stream_safe(text)
+}
+
+impl Dataset
+{
+    /// количество нестартеров в начале и в конце NFKD-декомпозиции кодпоинта
+    pub fn nonstarters(&self, code: u32) -> NonStarters
+    {
+        let ccc = |code: u32| self.unicode().get(&code).map_or(0, |c| c.ccc.u8());
+
+        let decomposition: Vec<u8> = match self.nfkd().get(&code) {
+            Some(decomposition) if !decomposition.is_empty() => {
+                decomposition.iter().map(|c| c.ccc.u8()).collect()
+            }
+            _ => vec![ccc(code)],
+        };
+
+        let leading = decomposition.iter().take_while(|&&ccc| ccc != 0).count();
+        let trailing = decomposition
+            .iter()
+            .rev()
+            .take_while(|&&ccc| ccc != 0)
+            .count();
+
+        NonStarters {
+            leading,
+            trailing,
+            all: leading == decomposition.len(),
+        }
+    }
+
+    /// находится ли текст в Stream-Safe Text Format
+    pub fn is_stream_safe(&self, text: &str) -> bool
+    {
+        let mut counter = NonStartersCounter::default();
+
+        text.chars()
+            .all(|c| counter.push(self.nonstarters(u32::from(c))))
+    }
+
+    /// привести текст к Stream-Safe Text Format - вставить CGJ перед кодпоинтом,
+    /// после которого нестартеров подряд стало бы больше 30
+    pub fn stream_safe(&self, text: &str) -> String
+    {
+        let mut counter = NonStartersCounter::default();
+        let mut result = String::with_capacity(text.len());
+
+        for c in text.chars() {
+            if !counter.push(self.nonstarters(u32::from(c))) {
+                result.push(CGJ);
+            }
+
+            result.push(c);
+        }
+
+        result
+    }
+}
+
+/// итератор нормализованных символов
+#[derive(Debug, Clone)]
+pub struct NormalizedChars<'a, I>
+{
+    normalizer: Normalizer<'a>,
+    chars: I,
+    /// текущий сегмент, декомпозированный
+    segment: Vec<u32>,
+    /// нестартеры подряд во входном тексте
+    nonstarters: NonStartersCounter,
+    /// нормализованные кодпоинты, готовые к выдаче
+    ready: VecDeque<u32>,
+}
+
+impl<'a, I: Iterator<Item = char>> NormalizedChars<'a, I>
+{
+    pub(crate) fn new(normalizer: Normalizer<'a>, chars: I) -> Self
+    {
+        Self {
+            normalizer,
+            chars,
+            segment: vec![],
+            nonstarters: NonStartersCounter::default(),
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// завершить текущий сегмент и перенести его в очередь на выдачу
+    fn flush(&mut self)
+    {
+        self.normalizer.finish_segment(&mut self.segment);
+        self.ready.extend(self.segment.drain(..));
+    }
+
+    /// добавить декомпозицию очередного символа, при необходимости - предварив её CGJ
+    fn push(&mut self, c: char)
+    {
+        if !self.nonstarters.push(self.normalizer.nonstarters(c)) {
+            self.push_code(u32::from(CGJ));
+        }
+
+        for code in self.normalizer.decompose(c) {
+            self.push_code(code);
+        }
+    }
+
+    /// добавить кодпоинт декомпозиции, завершив сегмент, если кодпоинт начинает новый
+    fn push_code(&mut self, code: u32)
+    {
+        if !self.segment.is_empty() && self.normalizer.is_segment_start(&mut self.segment, code) {
+            self.flush();
+        }
+
+        self.segment.push(code);
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizedChars<'_, I>
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<char>
+    {
+        loop {
+            if let Some(code) = self.ready.pop_front() {
+                // декомпозиция и композиция дают только скалярные значения
+                return Some(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+
+            match self.chars.next() {
+                Some(c) => self.push(c),
+                None if self.segment.is_empty() => return None,
+                None => self.flush(),
+            }
+        }
+    }
+}
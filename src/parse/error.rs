@@ -0,0 +1,226 @@
+use crate::codepoint::PropertiesError;
+use crate::SourceFile;
+
+/// ошибка разбора файла-источника UCD / UCA / CLDR
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError
+{
+    /// файл-источник
+    pub file: SourceFile,
+    /// номер строки, начиная с 1; 0 - ошибка относится ко всему файлу
+    pub line: usize,
+    /// номер поля (колонки, разделённой ';'), начиная с 0
+    pub field: usize,
+    /// текст, который не удалось разобрать
+    pub text: String,
+    /// причина ошибки
+    pub kind: ParseErrorKind,
+}
+
+/// причина ошибки разбора
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind
+{
+    /// отсутствует разделитель
+    MissingSeparator(&'static str),
+    /// некорректное количество полей
+    FieldsCount
+    {
+        expected: usize, found: usize
+    },
+    /// некорректное шестнадцатеричное значение
+    InvalidHex,
+    /// значение не является кодпоинтом (скалярным значением Unicode)
+    InvalidCodepoint,
+    /// значение выходит за допустимые пределы
+    OutOfRange,
+    /// запись повторяется
+    Duplicate,
+    /// некорректное значение свойства
+    Property(PropertiesError),
+    /// запись не соответствует формату файла
+    Malformed(&'static str),
+    /// файла-источника нет в наборе данных
+    MissingSource,
+}
+
+impl core::fmt::Display for ParseErrorKind
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match self {
+            Self::MissingSeparator(separator) => {
+                write!(f, "отсутствует разделитель '{}'", separator)
+            }
+            Self::FieldsCount { expected, found } => {
+                write!(f, "ожидается полей: {}, найдено: {}", expected, found)
+            }
+            Self::InvalidHex => f.write_str("некорректное шестнадцатеричное значение"),
+            Self::InvalidCodepoint => f.write_str("значение не является кодпоинтом"),
+            Self::OutOfRange => f.write_str("значение выходит за допустимые пределы"),
+            Self::Duplicate => f.write_str("повторяющаяся запись"),
+            Self::Property(error) => write!(f, "{}", error),
+            Self::Malformed(description) => f.write_str(description),
+            Self::MissingSource => f.write_str("файла нет в наборе данных"),
+        }
+    }
+}
+
+impl core::fmt::Display for ParseError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(
+            f,
+            "{}:{}, поле {}: {}: \"{}\"",
+            self.file.file_names()[0],
+            self.line,
+            self.field,
+            self.kind,
+            self.text
+        )
+    }
+}
+
+impl ParseError
+{
+    /// ошибка - файла-источника нет в наборе данных
+    pub(crate) fn missing_source(file: SourceFile, text: &str) -> Self
+    {
+        Self {
+            file,
+            line: 0,
+            field: 0,
+            text: text.to_owned(),
+            kind: ParseErrorKind::MissingSource,
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// строка разбираемого файла - для построения ошибок с контекстом
+#[derive(Clone, Copy)]
+pub(crate) struct Line<'a>
+{
+    pub file: SourceFile,
+    /// номер строки, начиная с 1
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a>
+{
+    /// строки файла с номерами
+    pub fn iter(file: SourceFile, source: &'a str) -> impl Iterator<Item = Line<'a>>
+    {
+        source.lines().enumerate().map(move |(i, text)| Line {
+            file,
+            number: i + 1,
+            text,
+        })
+    }
+
+    /// ошибка в поле строки
+    pub fn error(&self, field: usize, text: &str, kind: ParseErrorKind) -> ParseError
+    {
+        ParseError {
+            file: self.file,
+            line: self.number,
+            field,
+            text: text.to_owned(),
+            kind,
+        }
+    }
+
+    /// разделить текст по разделителю
+    pub fn split_once<'s>(
+        &self,
+        field: usize,
+        text: &'s str,
+        separator: &'static str,
+    ) -> Result<(&'s str, &'s str), ParseError>
+    {
+        text.split_once(separator)
+            .ok_or_else(|| self.error(field, text, ParseErrorKind::MissingSeparator(separator)))
+    }
+
+    /// шестнадцатеричное значение
+    pub fn hex(&self, field: usize, text: &str) -> Result<u32, ParseError>
+    {
+        u32::from_str_radix(text.trim(), 16)
+            .map_err(|_| self.error(field, text, ParseErrorKind::InvalidHex))
+    }
+
+    /// шестнадцатеричное значение, не более 16 бит
+    pub fn hex_u16(&self, field: usize, text: &str) -> Result<u16, ParseError>
+    {
+        u16::from_str_radix(text.trim(), 16)
+            .map_err(|_| self.error(field, text, ParseErrorKind::InvalidHex))
+    }
+
+    /// кодпоинт в шестнадцатеричном виде
+    pub fn char(&self, field: usize, text: &str) -> Result<char, ParseError>
+    {
+        char::from_u32(self.hex(field, text)?)
+            .ok_or_else(|| self.error(field, text, ParseErrorKind::InvalidCodepoint))
+    }
+
+    /// последовательность байт в шестнадцатеричном виде, разделенных пробелами
+    pub fn hex_bytes(&self, field: usize, text: &str) -> Result<Vec<u8>, ParseError>
+    {
+        text.split_whitespace()
+            .map(|byte| {
+                u8::from_str_radix(byte, 16)
+                    .map_err(|_| self.error(field, byte, ParseErrorKind::InvalidHex))
+            })
+            .collect()
+    }
+
+    /// последовательность кодов, разделенных пробелами
+    pub fn codes(&self, field: usize, text: &str) -> Result<Vec<u32>, ParseError>
+    {
+        text.split_whitespace()
+            .map(|code| self.hex(field, code))
+            .collect()
+    }
+
+    /// диапазон кодов вида XXXX..YYYY или одиночный код, не выходящий за U+10FFFF
+    pub fn range(&self, field: usize, text: &str) -> Result<(u32, u32), ParseError>
+    {
+        let text = text.trim();
+
+        let (first, last) = match text.split_once("..") {
+            Some((from, to)) => (self.hex(field, from)?, self.hex(field, to)?),
+            None => {
+                let code = self.hex(field, text)?;
+                (code, code)
+            }
+        };
+
+        if last > 0x10FFFF {
+            return Err(self.error(field, text, ParseErrorKind::OutOfRange));
+        }
+
+        if first > last {
+            return Err(self.error(
+                field,
+                text,
+                ParseErrorKind::Malformed("начало диапазона больше конца"),
+            ));
+        }
+
+        Ok((first, last))
+    }
+
+    /// значение свойства
+    pub fn property<T>(
+        &self,
+        field: usize,
+        text: &str,
+        value: Result<T, PropertiesError>,
+    ) -> Result<T, ParseError>
+    {
+        value.map_err(|error| self.error(field, text, ParseErrorKind::Property(error)))
+    }
+}
@@ -1,5 +1,7 @@
+mod error;
 mod uca_cldr;
 mod ucd;
 
+pub use error::*;
 pub use uca_cldr::*;
 pub use ucd::*;
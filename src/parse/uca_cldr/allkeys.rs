@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
-const ALLKEYS_UCA: &str = include_str!("./../../../data/uca 17.0.0/allkeys.txt");
-const ALLKEYS_CLDR: &str = include_str!("./../../../data/cldr 48/allkeys_CLDR.txt");
+use crate::parse::{Line, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
 
 /// запись таблицы DUCET / адаптированной таблицы DUCET для CLDR, полученная из allkeys.txt UCA / CLDR
 #[derive(Debug, Clone)]
@@ -51,7 +53,9 @@ impl core::fmt::Debug for Weights
 #[derive(Debug, Clone, PartialEq)]
 pub struct TrieNode
 {
-    pub weights: &'static Vec<Weights>,
+    pub weights: Vec<Weights>,
+    /// узел соответствует записи таблицы, а не только префиксу более длинного сокращения
+    pub is_entry: bool,
     pub children: Option<HashMap<u32, TrieNode>>,
 }
 
@@ -67,68 +71,52 @@ impl TrieNode
     }
 }
 
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// DUCET в виде дерева
-    pub static ref DUCET_TRIE: HashMap<u32, TrieNode> = weights_trie(&DUCET);
+    pub static ref DUCET_TRIE: &'static HashMap<u32, TrieNode> =
+        UnicodeVersion::default().dataset().ducet_trie();
     /// таблица DUCET из allkeys.txt
-    pub static ref DUCET: Vec<WeightsEntry> = allkeys(ALLKEYS_UCA);
+    pub static ref DUCET: &'static Vec<WeightsEntry> = UnicodeVersion::default().dataset().ducet();
 
     /// CLDR UND в виде дерева
-    pub static ref CLDR_UND_TRIE: HashMap<u32, TrieNode> = weights_trie(&CLDR_UND);
+    pub static ref CLDR_UND_TRIE: &'static HashMap<u32, TrieNode> =
+        UnicodeVersion::default().dataset().cldr_und_trie();
     /// таблица DUCET из allkeys.txt, адаптированная для CLDR
-    pub static ref CLDR_UND: Vec<WeightsEntry> = allkeys(ALLKEYS_CLDR);
+    pub static ref CLDR_UND: &'static Vec<WeightsEntry> =
+        UnicodeVersion::default().dataset().cldr_und();
 }
 
-fn allkeys(source: &str) -> Vec<WeightsEntry>
+pub(crate) fn allkeys(file: SourceFile, source: &str) -> Result<Vec<WeightsEntry>, ParseError>
 {
     let mut allkeys = vec![];
 
-    for line in source.lines() {
-        if line.starts_with('#') || line.is_empty() || line.starts_with("@version") {
+    for line in Line::iter(file, source) {
+        if line.text.starts_with('#') || line.text.is_empty() || line.text.starts_with("@version") {
             continue;
         }
 
-        if line.starts_with("@implicitweights") {
+        // диапазоны вычисляемых весов разбираются отдельно, в implicit_weights
+        if line.text.starts_with("@implicitweights") {
             continue;
         }
 
-        let (values, description) = line.split_once(" # ").unwrap();
-        let (codes, weights) = values.split_once(" ; ").unwrap();
+        let (values, description) = line.text.split_once(" # ").unwrap_or((line.text, ""));
+        let (codes, weights) = line.split_once(0, values, " ; ")?;
 
         let description = description.to_string();
+        let codes = line.codes(0, codes)?;
 
-        let codes: Vec<u32> = codes
-            .trim()
-            .split(' ')
-            .map(|v| u32::from_str_radix(v, 16).unwrap())
-            .collect();
+        if codes.is_empty() || codes.len() > 3 {
+            return Err(line.error(0, values, ParseErrorKind::OutOfRange));
+        }
 
-        let weights: Vec<Weights> = weights
+        let weights = weights
             .trim_matches([' ', '[', ']'])
             .split("][")
-            .map(|str| {
-                let is_variable = str.chars().collect::<Vec<char>>()[0] == '*';
-                let weights = str
-                    .trim_matches(['.', '*'])
-                    .split('.')
-                    .map(|w| {
-                        assert!(!w.is_empty());
-                        assert!(w.chars().all(|c| c.is_ascii_alphanumeric()));
-
-                        u16::from_str_radix(w, 16).unwrap()
-                    })
-                    .collect::<Vec<u16>>();
-
-                assert_eq!(weights.len(), 3);
-
-                Weights {
-                    l1: weights[0],
-                    l2: weights[1],
-                    l3: weights[2],
-                    is_variable,
-                }
-            })
-            .collect();
+            .filter(|str| !str.is_empty())
+            .map(|str| parse_weights(&line, str))
+            .collect::<Result<Vec<Weights>, ParseError>>()?;
 
         allkeys.push(WeightsEntry {
             codes,
@@ -139,65 +127,74 @@ fn allkeys(source: &str) -> Vec<WeightsEntry>
 
     allkeys.sort_by_key(|e| e.codes.len());
 
-    allkeys
+    Ok(allkeys)
+}
+
+/// веса одного элемента сопоставления вида .XXXX.XXXX.XXXX / *XXXX.XXXX.XXXX
+fn parse_weights(line: &Line, str: &str) -> Result<Weights, ParseError>
+{
+    let is_variable = match str.chars().next() {
+        Some('*') => true,
+        Some('.') => false,
+        _ => return Err(line.error(1, str, ParseErrorKind::Malformed("ожидается '.' или '*'"))),
+    };
+
+    let weights = str[1 ..]
+        .split('.')
+        .map(|w| line.hex_u16(1, w))
+        .collect::<Result<Vec<u16>, ParseError>>()?;
+
+    if weights.len() != 3 {
+        return Err(line.error(
+            1,
+            str,
+            ParseErrorKind::FieldsCount {
+                expected: 3,
+                found: weights.len(),
+            },
+        ));
+    }
+
+    Ok(Weights {
+        l1: weights[0],
+        l2: weights[1],
+        l3: weights[2],
+        is_variable,
+    })
 }
 
-#[inline]
-fn weights_trie(table: &'static Vec<WeightsEntry>) -> HashMap<u32, TrieNode>
+/// дерево весов. если в таблице нет префикса сокращения (в DUCET: 0FB2 0F71 для 0FB2 0F71 0F80),
+/// он добавляется промежуточным узлом без весов
+pub(crate) fn weights_trie(table: &[WeightsEntry]) -> HashMap<u32, TrieNode>
 {
     let mut roots = HashMap::new();
 
+    // записи отсортированы по длине последовательности кодов, префиксы добавляются раньше сокращений
     for entry in table.iter() {
-        let codes = &entry.codes;
-
-        match codes.len() {
-            1 => {
-                roots.insert(
-                    codes[0],
-                    TrieNode {
-                        weights: &entry.weights,
-                        children: None,
-                    },
-                );
-            }
-            2 => {
-                roots.entry(codes[0]).and_modify(|root| {
-                    if root.children.is_none() {
-                        root.children = Some(HashMap::new());
-                    }
-
-                    root.children.as_mut().unwrap().insert(
-                        codes[1],
-                        TrieNode {
-                            weights: &entry.weights,
-                            children: None,
-                        },
-                    );
-                });
-            }
-            3 => {
-                roots.entry(codes[0]).and_modify(|root| {
-                    root.children
-                        .as_mut()
-                        .unwrap()
-                        .entry(codes[1])
-                        .and_modify(|second| {
-                            if second.children.is_none() {
-                                second.children = Some(HashMap::new());
-                            }
-
-                            second.children.as_mut().unwrap().insert(
-                                codes[2],
-                                TrieNode {
-                                    weights: &entry.weights,
-                                    children: None,
-                                },
-                            );
-                        });
-                });
-            }
-            _ => unreachable!(),
+        let (&last, prefix) = entry.codes.split_last().unwrap();
+        let mut level = &mut roots;
+
+        for code in prefix {
+            let node = level.entry(*code).or_insert(TrieNode {
+                weights: vec![],
+                is_entry: false,
+                children: None,
+            });
+
+            level = node.children.get_or_insert_with(HashMap::new);
         }
+
+        level
+            .entry(last)
+            .and_modify(|node: &mut TrieNode| {
+                node.weights = entry.weights.clone();
+                node.is_entry = true;
+            })
+            .or_insert_with(|| TrieNode {
+                weights: entry.weights.clone(),
+                is_entry: true,
+                children: None,
+            });
     }
 
     roots
@@ -1,17 +1,14 @@
-
-const COLLATION_TEST_DUCET_NON_IGNORABLE_SOURCE: &str =
-    include_str!("./../../../data/uca 17.0.0/CollationTest/CollationTest_NON_IGNORABLE.txt");
-const COLLATION_TEST_DUCET_SHIFTED_SOURCE: &str =
-    include_str!("./../../../data/uca 17.0.0/CollationTest/CollationTest_SHIFTED.txt");
-const COLLATION_TEST_CLDR_NON_IGNORABLE_SOURCE: &str =
-    include_str!("./../../../data/cldr 48/CollationTest/CollationTest_CLDR_NON_IGNORABLE.txt");
-const COLLATION_TEST_CLDR_SHIFTED_SOURCE: &str =
-    include_str!("./../../../data/cldr 48/CollationTest/CollationTest_CLDR_SHIFTED.txt");
+use crate::parse::{Line, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
 
 /// тест сопоставлений
 #[derive(Debug, Clone)]
 pub struct CollationTest
 {
+    /// номер строки в файле теста
+    pub line: usize,
     /// последовательность кодов
     pub codes: Vec<u32>,
     /// L1
@@ -38,84 +35,82 @@ impl CollationTest
     }
 }
 
-macro_rules! collation_test {
-    ($fn:ident, $pub:ident, $source:expr) => {
-        lazy_static! {
-            pub static ref $pub: Vec<CollationTest> = $fn();
-        }
-
-        fn $fn() -> Vec<CollationTest>
-        {
-            parse_collation_test($source)
-        }
-    };
+#[cfg(feature = "embedded")]
+lazy_static! {
+    pub static ref COLLATION_TEST_DUCET_NON_IGNORABLE: &'static Vec<CollationTest> =
+        UnicodeVersion::default()
+            .dataset()
+            .collation_test_ducet_non_ignorable();
+    pub static ref COLLATION_TEST_DUCET_SHIFTED: &'static Vec<CollationTest> =
+        UnicodeVersion::default()
+            .dataset()
+            .collation_test_ducet_shifted();
+    pub static ref COLLATION_TEST_CLDR_NON_IGNORABLE: &'static Vec<CollationTest> =
+        UnicodeVersion::default()
+            .dataset()
+            .collation_test_cldr_non_ignorable();
+    pub static ref COLLATION_TEST_CLDR_SHIFTED: &'static Vec<CollationTest> =
+        UnicodeVersion::default()
+            .dataset()
+            .collation_test_cldr_shifted();
 }
 
-collation_test!(
-    collation_test_ducet_non_ignorable,
-    COLLATION_TEST_DUCET_NON_IGNORABLE,
-    COLLATION_TEST_DUCET_NON_IGNORABLE_SOURCE
-);
-collation_test!(
-    collation_test_ducet_shifted,
-    COLLATION_TEST_DUCET_SHIFTED,
-    COLLATION_TEST_DUCET_SHIFTED_SOURCE
-);
-collation_test!(
-    collation_test_cldr_non_ignorable,
-    COLLATION_TEST_CLDR_NON_IGNORABLE,
-    COLLATION_TEST_CLDR_NON_IGNORABLE_SOURCE
-);
-collation_test!(
-    collation_test_cldr_shifted,
-    COLLATION_TEST_CLDR_SHIFTED,
-    COLLATION_TEST_CLDR_SHIFTED_SOURCE
-);
-
-fn parse_collation_test(source: &str) -> Vec<CollationTest>
+/// разбор CollationTest_*.txt, в том числе сокращенных версий (*_SHORT.txt) - без весов и описаний
+pub(crate) fn parse_collation_test(
+    file: SourceFile,
+    source: &str,
+) -> Result<Vec<CollationTest>, ParseError>
 {
     let mut result = vec![];
 
-    source
-        .lines()
-        .filter(|line| !line.is_empty() && !line.starts_with('#'))
-        .for_each(|line| {
-            let (codes, description) = line.split_once(';').unwrap();
+    for line in Line::iter(file, source) {
+        if line.text.is_empty() || line.text.starts_with('#') {
+            continue;
+        }
+
+        let (codes, description) = line.text.split_once(';').unwrap_or((line.text, ""));
 
-            let (description, weights) = description.split_at(description.rfind('[').unwrap());
-            let description = description.trim_matches(['\t', ' ', '#']).to_string();
+        let (description, weights) =
+            description.split_at(description.rfind('[').unwrap_or(description.len()));
+        let description = description.trim_matches(['\t', ' ', '#']).to_string();
 
-            let codes: Vec<u32> = codes
-                .split_whitespace()
-                .map(|code| u32::from_str_radix(code, 16).unwrap())
-                .collect();
+        let codes = line.codes(0, codes)?;
 
-            let mut weights: Vec<Vec<u16>> = weights
-                .trim_matches(['[', ']'])
-                .trim_end_matches('|')
-                .split('|')
-                .map(|weights| {
-                    weights
-                        .trim()
-                        .split_whitespace()
-                        .map(|weight| u16::from_str_radix(weight, 16).unwrap())
-                        .collect()
-                })
-                .collect();
+        let mut weights = weights
+            .trim_matches(['[', ']'])
+            .trim_end_matches('|')
+            .split('|')
+            .map(|weights| {
+                weights
+                    .split_whitespace()
+                    .map(|weight| line.hex_u16(1, weight))
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<u16>>, ParseError>>()?;
 
-            assert!(weights.len() <= 4);
+        if weights.len() > 4 {
+            return Err(line.error(
+                1,
+                line.text,
+                ParseErrorKind::FieldsCount {
+                    expected: 4,
+                    found: weights.len(),
+                },
+            ));
+        }
 
-            (0 .. 4 - weights.len()).for_each(|_| weights.push(vec![]));
+        weights.resize(4, vec![]);
 
-            result.push(CollationTest {
-                codes,
-                l1: weights[0].clone(),
-                l2: weights[1].clone(),
-                l3: weights[2].clone(),
-                l4: weights[3].clone(),
-                description,
-            });
+        result.push(CollationTest {
+            line: line.number,
+            codes,
+            l1: weights[0].clone(),
+            l2: weights[1].clone(),
+            l3: weights[2].clone(),
+            l4: weights[3].clone(),
+            description,
         });
+    }
 
-    result
+    Ok(result)
 }
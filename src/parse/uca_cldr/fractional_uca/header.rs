@@ -0,0 +1,539 @@
+use crate::parse::{Line, ParseError, ParseErrorKind};
+
+use super::format_bytes;
+
+/// запись заголовка Fractional UCA - строка вида [...]
+///
+/// https://www.unicode.org/reports/tr35/tr35-collation.html#Root_Data_Files
+#[derive(Debug, Clone, PartialEq)]
+pub enum FractionalUcaHeader
+{
+    /// [UCA version = 17.0.0]
+    UcaVersion(String),
+    /// [Unified_Ideograph 4E00..9FFF FA0E..FA0F ...] - диапазоны унифицированных иероглифов
+    UnifiedIdeograph(Vec<(u32, u32)>),
+    /// [radical 1=⼀一:一𪛙丁-丆...] - иероглифы радикала в порядке radical-stroke
+    Radical(Radical),
+    /// [radical end]
+    RadicalEnd,
+    /// [top_byte 63 Glag Perm COMPRESS ]  # 88 primary weights
+    TopByte(TopByte),
+    /// [categories Zs 03{SPACE}=1 04{SPACE}=17 ... ]
+    Categories
+    {
+        /// общая категория
+        category: String,
+        /// старшие байты праймари: байт, группы, количество праймари
+        top_bytes: Vec<(u8, Vec<String>, u32)>,
+    },
+    /// [first regular [0C 04, 05, 05]] # U+0060 GRAVE ACCENT
+    Boundary(Boundary),
+    /// [variable top = 0B FF FF FF]
+    VariableTop(Vec<u8>),
+    /// [reorderingTokens Adlm 7B=69 ]
+    ReorderingTokens
+    {
+        /// скрипт или специальная группа
+        token: String,
+        /// старшие байты праймари и количество праймари
+        top_bytes: Vec<(u8, u32)>,
+    },
+    /// [fixed first implicit byte E0]
+    Fixed(FixedByte, u8),
+}
+
+/// радикал и относящиеся к нему иероглифы
+#[derive(Debug, Clone, PartialEq)]
+pub struct Radical
+{
+    /// номер радикала по Канси
+    pub number: u16,
+    /// вариант радикала - количество штрихов после номера: 90', 182''
+    pub primes: u8,
+    /// символы радикала
+    pub names: String,
+    /// иероглифы: одиночные и диапазоны, в порядке radical-stroke
+    pub codes: Vec<(u32, u32)>,
+}
+
+/// старший байт праймари
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopByte
+{
+    pub byte: u8,
+    /// скрипты / специальные группы, праймари которых начинаются с этого байта
+    pub groups: Vec<String>,
+    /// праймари группы сжимаемые
+    pub compress: bool,
+    /// количество праймари группы (указывается для первого байта группы)
+    pub primary_weights: Option<u32>,
+}
+
+/// граница: первый / последний элемент сопоставления определённого вида
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boundary
+{
+    /// first / last
+    pub first: bool,
+    pub position: BoundaryPosition,
+    /// веса трёх уровней, None - вес не определён (X)
+    pub weights: [Option<Vec<u8>>; 3],
+    /// комментарий: кодпоинт, которому соответствуют веса, или CONSTRUCTED
+    pub comment: String,
+}
+
+/// вид элемента сопоставления, для которого указана граница
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundaryPosition
+{
+    TertiaryIgnorable,
+    TertiaryInSecondaryNonIgnorable,
+    SecondaryIgnorable,
+    SecondaryInPrimaryNonIgnorable,
+    PrimaryIgnorable,
+    Variable,
+    Regular,
+    Implicit,
+    Trailing,
+}
+
+impl BoundaryPosition
+{
+    /// все виды
+    pub const ALL: [Self; 9] = [
+        Self::TertiaryIgnorable,
+        Self::TertiaryInSecondaryNonIgnorable,
+        Self::SecondaryIgnorable,
+        Self::SecondaryInPrimaryNonIgnorable,
+        Self::PrimaryIgnorable,
+        Self::Variable,
+        Self::Regular,
+        Self::Implicit,
+        Self::Trailing,
+    ];
+
+    /// название в Fractional UCA
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::TertiaryIgnorable => "tertiary ignorable",
+            Self::TertiaryInSecondaryNonIgnorable => "tertiary in secondary non-ignorable",
+            Self::SecondaryIgnorable => "secondary ignorable",
+            Self::SecondaryInPrimaryNonIgnorable => "secondary in primary non-ignorable",
+            Self::PrimaryIgnorable => "primary ignorable",
+            Self::Variable => "variable",
+            Self::Regular => "regular",
+            Self::Implicit => "implicit",
+            Self::Trailing => "trailing",
+        }
+    }
+}
+
+/// фиксированные значения байтов весов
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixedByte
+{
+    FirstImplicit,
+    LastImplicit,
+    FirstTrail,
+    LastTrail,
+    FirstSpecial,
+    LastSpecial,
+    SecondaryCommon,
+    LastSecondaryCommon,
+    FirstIgnorableSecondary,
+    TertiaryCommon,
+    FirstIgnorableTertiary,
+}
+
+impl FixedByte
+{
+    /// все значения
+    pub const ALL: [Self; 11] = [
+        Self::FirstImplicit,
+        Self::LastImplicit,
+        Self::FirstTrail,
+        Self::LastTrail,
+        Self::FirstSpecial,
+        Self::LastSpecial,
+        Self::SecondaryCommon,
+        Self::LastSecondaryCommon,
+        Self::FirstIgnorableSecondary,
+        Self::TertiaryCommon,
+        Self::FirstIgnorableTertiary,
+    ];
+
+    /// название в Fractional UCA
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::FirstImplicit => "first implicit",
+            Self::LastImplicit => "last implicit",
+            Self::FirstTrail => "first trail",
+            Self::LastTrail => "last trail",
+            Self::FirstSpecial => "first special",
+            Self::LastSpecial => "last special",
+            Self::SecondaryCommon => "secondary common",
+            Self::LastSecondaryCommon => "last secondary common",
+            Self::FirstIgnorableSecondary => "first ignorable secondary",
+            Self::TertiaryCommon => "tertiary common",
+            Self::FirstIgnorableTertiary => "first ignorable tertiary",
+        }
+    }
+}
+
+impl FractionalUcaHeader
+{
+    /// в виде, в котором запись представлена в Fractional UCA
+    pub fn formatted(&self) -> String
+    {
+        match self {
+            Self::UcaVersion(version) => format!("[UCA version = {}]", version),
+            Self::UnifiedIdeograph(ranges) => {
+                let ranges: Vec<String> = ranges
+                    .iter()
+                    .map(|&(first, last)| match first == last {
+                        true => format!("{:04X}", first),
+                        false => format!("{:04X}..{:04X}", first, last),
+                    })
+                    .collect();
+
+                format!("[Unified_Ideograph {}]", ranges.join(" "))
+            }
+            Self::Radical(radical) => {
+                let codes: String = radical
+                    .codes
+                    .iter()
+                    .map(|&(first, last)| {
+                        let char = |code| char::from_u32(code).unwrap_or_default();
+
+                        match first == last {
+                            true => char(first).to_string(),
+                            false => format!("{}-{}", char(first), char(last)),
+                        }
+                    })
+                    .collect();
+
+                format!(
+                    "[radical {}{}={}:{}]",
+                    radical.number,
+                    "'".repeat(radical.primes as usize),
+                    radical.names,
+                    codes
+                )
+            }
+            Self::RadicalEnd => "[radical end]".to_owned(),
+            Self::TopByte(top_byte) => {
+                let mut result = format!(
+                    "[top_byte\t{:02X}\t{}{} ]",
+                    top_byte.byte,
+                    top_byte.groups.join(" "),
+                    if top_byte.compress { "\tCOMPRESS" } else { "" }
+                );
+
+                if let Some(count) = top_byte.primary_weights {
+                    result.push_str(format!("  # {} primary weights", count).as_str());
+                }
+
+                result
+            }
+            Self::Categories {
+                category,
+                top_bytes,
+            } => {
+                let top_bytes: Vec<String> = top_bytes
+                    .iter()
+                    .map(|(byte, groups, count)| {
+                        format!("{:02X}{{{}}}={}", byte, groups.join(" "), count)
+                    })
+                    .collect();
+
+                format!("[categories\t{}\t{} ]", category, top_bytes.join(" "))
+            }
+            Self::Boundary(boundary) => {
+                let weights = boundary
+                    .weights
+                    .iter()
+                    .map(|weight| match weight {
+                        Some(weight) => format_bytes(weight),
+                        None => "X".to_owned(),
+                    })
+                    .collect::<Vec<String>>();
+
+                let mut result = format!(
+                    "[{} {} [{}]]",
+                    if boundary.first { "first" } else { "last" },
+                    boundary.position.as_str(),
+                    format_levels(&weights)
+                );
+
+                if !boundary.comment.is_empty() {
+                    result.push_str(format!(" # {}", boundary.comment).as_str());
+                }
+
+                result
+            }
+            Self::VariableTop(weight) => format!("[variable top = {}]", format_bytes(weight)),
+            Self::ReorderingTokens { token, top_bytes } => {
+                let top_bytes: Vec<String> = top_bytes
+                    .iter()
+                    .map(|(byte, count)| format!("{:02X}={}", byte, count))
+                    .collect();
+
+                format!("[reorderingTokens\t{}\t{} ]", token, top_bytes.join(" "))
+            }
+            Self::Fixed(fixed, byte) => format!("[fixed {} byte {:02X}]", fixed.as_str(), byte),
+        }
+    }
+}
+
+/// разобрать строку заголовка вида [...]
+pub(crate) fn parse_header(line: &Line) -> Result<FractionalUcaHeader, ParseError>
+{
+    let (record, comment) = line.text.split_once('#').unwrap_or((line.text, ""));
+    let comment = comment.trim();
+
+    let record = record
+        .trim_end()
+        .strip_prefix('[')
+        .and_then(|record| record.strip_suffix(']'))
+        .ok_or_else(|| line.error(0, line.text, ParseErrorKind::Malformed("ожидается [...]")))?;
+
+    if let Some(version) = record.strip_prefix("UCA version = ") {
+        return Ok(FractionalUcaHeader::UcaVersion(version.trim().to_owned()));
+    }
+
+    if let Some(ranges) = record.strip_prefix("Unified_Ideograph ") {
+        return Ok(FractionalUcaHeader::UnifiedIdeograph(
+            ranges
+                .split_whitespace()
+                .map(|range| line.range(0, range))
+                .collect::<Result<_, _>>()?,
+        ));
+    }
+
+    if record == "radical end" {
+        return Ok(FractionalUcaHeader::RadicalEnd);
+    }
+
+    if let Some(radical) = record.strip_prefix("radical ") {
+        return parse_radical(line, radical);
+    }
+
+    if let Some(top_byte) = record.strip_prefix("top_byte\t") {
+        let fields: Vec<&str> = top_byte.split('\t').map(|f| f.trim()).collect();
+
+        if !(2 ..= 3).contains(&fields.len()) || (fields.len() == 3 && fields[2] != "COMPRESS") {
+            return Err(line.error(
+                0,
+                top_byte,
+                ParseErrorKind::Malformed("некорректный top_byte"),
+            ));
+        }
+
+        let primary_weights = match comment.strip_suffix(" primary weights") {
+            Some(count) => Some(
+                count
+                    .parse()
+                    .map_err(|_| line.error(1, count, ParseErrorKind::OutOfRange))?,
+            ),
+            None => None,
+        };
+
+        return Ok(FractionalUcaHeader::TopByte(TopByte {
+            byte: hex_byte(line, 0, fields[0])?,
+            groups: fields[1].split_whitespace().map(String::from).collect(),
+            compress: fields.len() == 3,
+            primary_weights,
+        }));
+    }
+
+    if let Some(categories) = record.strip_prefix("categories\t") {
+        let (category, mut rest) = line.split_once(0, categories, "\t")?;
+        let mut top_bytes = vec![];
+
+        rest = rest.trim();
+
+        while !rest.is_empty() {
+            let (byte, tail) = line.split_once(0, rest, "{")?;
+            let (groups, tail) = line.split_once(0, tail, "}=")?;
+            let (count, tail) = tail.split_once(' ').unwrap_or((tail, ""));
+
+            top_bytes.push((
+                hex_byte(line, 0, byte)?,
+                groups.split_whitespace().map(String::from).collect(),
+                count_value(line, count)?,
+            ));
+
+            rest = tail.trim_start();
+        }
+
+        return Ok(FractionalUcaHeader::Categories {
+            category: category.to_owned(),
+            top_bytes,
+        });
+    }
+
+    if let Some(tokens) = record.strip_prefix("reorderingTokens\t") {
+        let (token, rest) = line.split_once(0, tokens, "\t")?;
+
+        let top_bytes = rest
+            .split_whitespace()
+            .map(|value| {
+                let (byte, count) = line.split_once(0, value, "=")?;
+                Ok((hex_byte(line, 0, byte)?, count_value(line, count)?))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        return Ok(FractionalUcaHeader::ReorderingTokens {
+            token: token.to_owned(),
+            top_bytes,
+        });
+    }
+
+    if let Some(weight) = record.strip_prefix("variable top = ") {
+        return Ok(FractionalUcaHeader::VariableTop(line.hex_bytes(0, weight)?));
+    }
+
+    if let Some(fixed) = record.strip_prefix("fixed ") {
+        let (name, byte) = line.split_once(0, fixed, " byte ")?;
+
+        let fixed = FixedByte::ALL
+            .into_iter()
+            .find(|fixed| fixed.as_str() == name)
+            .ok_or_else(|| line.error(0, name, ParseErrorKind::Malformed("неизвестный fixed")))?;
+
+        return Ok(FractionalUcaHeader::Fixed(fixed, hex_byte(line, 0, byte)?));
+    }
+
+    let (first, boundary) = match record.split_once(' ') {
+        Some(("first", boundary)) => (true, boundary),
+        Some(("last", boundary)) => (false, boundary),
+        _ => return Err(line.error(0, record, ParseErrorKind::Malformed("неизвестная запись"))),
+    };
+
+    let (position, weights) = line.split_once(0, boundary, "[")?;
+
+    let position = BoundaryPosition::ALL
+        .into_iter()
+        .find(|p| p.as_str() == position.trim())
+        .ok_or_else(|| {
+            line.error(
+                0,
+                position,
+                ParseErrorKind::Malformed("неизвестная граница"),
+            )
+        })?;
+
+    let weights = weights
+        .strip_suffix(']')
+        .ok_or_else(|| line.error(0, weights, ParseErrorKind::MissingSeparator("]")))?
+        .split(',')
+        .map(|weight| match weight.trim() {
+            "X" => Ok(None),
+            weight => line.hex_bytes(0, weight).map(Some),
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let weights: [Option<Vec<u8>>; 3] = weights.try_into().map_err(|weights: Vec<_>| {
+        line.error(
+            0,
+            boundary,
+            ParseErrorKind::FieldsCount {
+                expected: 3,
+                found: weights.len(),
+            },
+        )
+    })?;
+
+    Ok(FractionalUcaHeader::Boundary(Boundary {
+        first,
+        position,
+        weights,
+        comment: comment.to_owned(),
+    }))
+}
+
+/// [radical 90'=⺦丬:...]
+fn parse_radical(line: &Line, radical: &str) -> Result<FractionalUcaHeader, ParseError>
+{
+    let (number, rest) = line.split_once(0, radical, "=")?;
+    let (names, chars) = line.split_once(0, rest, ":")?;
+
+    let primes = number.len() - number.trim_end_matches('\'').len();
+    let number = number.trim_end_matches('\'');
+
+    let number = number
+        .parse()
+        .map_err(|_| line.error(0, number, ParseErrorKind::OutOfRange))?;
+
+    let mut codes: Vec<(u32, u32)> = vec![];
+    let mut chars = chars.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '-' => {
+                let last = chars.next().ok_or_else(|| {
+                    line.error(
+                        0,
+                        radical,
+                        ParseErrorKind::Malformed("незавершённый диапазон"),
+                    )
+                })?;
+
+                match codes.last_mut() {
+                    Some(range) if range.0 == range.1 && range.0 < last as u32 => {
+                        range.1 = last as u32
+                    }
+                    _ => {
+                        return Err(line.error(
+                            0,
+                            radical,
+                            ParseErrorKind::Malformed("некорректный диапазон"),
+                        ))
+                    }
+                }
+            }
+            c => codes.push((c as u32, c as u32)),
+        }
+    }
+
+    Ok(FractionalUcaHeader::Radical(Radical {
+        number,
+        primes: primes as u8,
+        names: names.to_owned(),
+        codes,
+    }))
+}
+
+/// байт в шестнадцатеричном виде
+fn hex_byte(line: &Line, field: usize, text: &str) -> Result<u8, ParseError>
+{
+    u8::from_str_radix(text.trim(), 16)
+        .map_err(|_| line.error(field, text, ParseErrorKind::InvalidHex))
+}
+
+/// количество праймари
+fn count_value(line: &Line, text: &str) -> Result<u32, ParseError>
+{
+    text.trim()
+        .parse()
+        .map_err(|_| line.error(0, text, ParseErrorKind::OutOfRange))
+}
+
+/// уровни весов границы через запятую: [,,], [,, 3D 02], [X, 7F 02, X]
+fn format_levels(levels: &[String]) -> String
+{
+    let mut result = levels[0].clone();
+
+    for level in &levels[1 ..] {
+        result.push(',');
+
+        if !level.is_empty() {
+            result.push(' ');
+            result.push_str(level);
+        }
+    }
+
+    result
+}
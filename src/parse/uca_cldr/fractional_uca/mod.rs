@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+
+use crate::parse::{Line, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+pub use header::*;
+
+mod header;
+
+/// значения второго и третьего байтов праймари (ICU, Collation::incThreeBytePrimaryByOffset)
+const TRAIL_BYTES: core::ops::RangeInclusive<u8> = 0x02 ..= 0xFF;
+/// значения второго байта праймари сжимаемых групп: 02, 03 и FF зарезервированы под сжатие
+const COMPRESSIBLE_TRAIL_BYTES: core::ops::RangeInclusive<u8> = 0x04 ..= 0xFE;
+
+/// таблица Fractional UCA: заголовок и записи
+///
+/// https://www.unicode.org/reports/tr35/tr35-collation.html#Root_Data_Files
+#[derive(Debug, Clone)]
+pub struct FractionalUca
+{
+    /// записи заголовка в порядке следования в файле
+    pub header: Vec<FractionalUcaHeader>,
+    /// записи таблицы
+    pub entries: Vec<FractionalWeightsEntry>,
+    /// порядковые номера иероглифов в порядке radical-stroke
+    han_order: HashMap<u32, u32>,
+    /// для каждой записи заголовка - количество предшествующих ей записей таблицы
+    header_positions: Vec<usize>,
+}
+
+/// запись таблицы Fractional UCA
+#[derive(Debug, Clone)]
+pub struct FractionalWeightsEntry
+{
+    /// последовательность кодов
+    pub codes: Vec<u32>,
+    /// веса, праймари U+xxxx заменены праймари иероглифов
+    pub weights: Vec<(Vec<u8>, Vec<u8>, Vec<u8>)>,
+    /// элементы сопоставления в том виде, в котором они записаны в файле
+    pub elements: Vec<FractionalElement>,
+    /// описание
+    pub description: String,
+    /// кейс L·, смотри https://www.unicode.org/reports/tr35/tr35-collation.html#Context_Sensitive_Mappings
+    pub ce_case: bool,
+}
+
+/// элемент сопоставления записи Fractional UCA
+#[derive(Debug, Clone, PartialEq)]
+pub enum FractionalElement
+{
+    /// веса, заданные явно: [0B 14 33, 05, 05]
+    Weights(Vec<u8>, Vec<u8>, Vec<u8>),
+    /// праймари иероглифа и, если указаны, секондари и терциари: [U+4E00], [U+4E00, 10], [U+4E36, 70, 05]
+    Han
+    {
+        code: u32,
+        secondary: Option<Vec<u8>>,
+        tertiary: Option<Vec<u8>>,
+    },
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// таблица c ремаппингом DUCET для использования с CLDR с весами переменной длины
+    ///
+    /// https://www.unicode.org/reports/tr35/tr35-collation.html#Root_Data_Files
+    pub static ref FRACTIONAL_UCA_TABLE: &'static Vec<FractionalWeightsEntry> =
+        &UnicodeVersion::default().dataset().fractional_uca().entries;
+}
+
+impl FractionalUca
+{
+    /// версия UCA, на основе которой построена таблица
+    pub fn uca_version(&self) -> Option<&str>
+    {
+        self.header.iter().find_map(|record| match record {
+            FractionalUcaHeader::UcaVersion(version) => Some(version.as_str()),
+            _ => None,
+        })
+    }
+
+    /// граница: первый / последний элемент сопоставления определённого вида
+    pub fn boundary(&self, first: bool, position: BoundaryPosition) -> Option<&Boundary>
+    {
+        self.header.iter().find_map(|record| match record {
+            FractionalUcaHeader::Boundary(boundary)
+                if boundary.first == first && boundary.position == position =>
+            {
+                Some(boundary)
+            }
+            _ => None,
+        })
+    }
+
+    /// фиксированное значение байта весов
+    pub fn fixed(&self, fixed: FixedByte) -> Option<u8>
+    {
+        self.header.iter().find_map(|record| match record {
+            FractionalUcaHeader::Fixed(f, byte) if *f == fixed => Some(*byte),
+            _ => None,
+        })
+    }
+
+    /// старший байт праймари
+    pub fn top_byte(&self, byte: u8) -> Option<&TopByte>
+    {
+        self.header.iter().find_map(|record| match record {
+            FractionalUcaHeader::TopByte(top_byte) if top_byte.byte == byte => Some(top_byte),
+            _ => None,
+        })
+    }
+
+    /// граница переменных весов
+    pub fn variable_top(&self) -> Option<&[u8]>
+    {
+        self.header.iter().find_map(|record| match record {
+            FractionalUcaHeader::VariableTop(weight) => Some(weight.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// праймари иероглифа: иероглифы в порядке radical-stroke получают последовательные
+    /// трёхбайтовые праймари, следующие за первым праймари группы Hani ([XX 02 02] - запись
+    /// "HAN first primary"), так же как генератор базовых данных ICU (genuca, вариант unihan)
+    /// перебирает их функцией Collation::incThreeBytePrimaryByOffset: третий байт - 02 ..= FF,
+    /// второй - 02 ..= FF или 04 ..= FE для сжимаемых групп, при переполнении увеличивается
+    /// старший байт
+    ///
+    /// https://github.com/unicode-org/icu/blob/main/icu4c/source/i18n/collation.cpp
+    pub fn han_primary(&self, code: u32) -> Option<Vec<u8>>
+    {
+        let index = *self.han_order.get(&code)?;
+
+        let top_bytes: Vec<&TopByte> = self
+            .header
+            .iter()
+            .filter_map(|record| match record {
+                FractionalUcaHeader::TopByte(top_byte)
+                    if top_byte.groups.iter().any(|group| group == "Hani") =>
+                {
+                    Some(top_byte)
+                }
+                _ => None,
+            })
+            .collect();
+
+        let second_bytes = match top_bytes.first()?.compress {
+            true => COMPRESSIBLE_TRAIL_BYTES,
+            false => TRAIL_BYTES,
+        };
+
+        let third = TRAIL_BYTES.len() as u32;
+        let second = second_bytes.len() as u32;
+
+        // первый праймари группы занят границей, иероглифы начинаются со следующего
+        let offset = index + 1;
+
+        Some(vec![
+            top_bytes.get((offset / third / second) as usize)?.byte,
+            second_bytes.start() + ((offset / third) % second) as u8,
+            TRAIL_BYTES.start() + (offset % third) as u8,
+        ])
+    }
+
+    /// веса элемента сопоставления, праймари U+xxxx заменяется праймари иероглифа
+    pub fn resolve(&self, element: &FractionalElement) -> Option<(Vec<u8>, Vec<u8>, Vec<u8>)>
+    {
+        match element {
+            FractionalElement::Weights(primary, secondary, tertiary) => {
+                Some((primary.clone(), secondary.clone(), tertiary.clone()))
+            }
+            FractionalElement::Han {
+                code,
+                secondary,
+                tertiary,
+            } => {
+                let common = |fixed, weight: &Option<Vec<u8>>| match weight {
+                    Some(weight) => weight.clone(),
+                    None => vec![self.fixed(fixed).unwrap_or(0x05)],
+                };
+
+                Some((
+                    self.han_primary(*code)?,
+                    common(FixedByte::SecondaryCommon, secondary),
+                    common(FixedByte::TertiaryCommon, tertiary),
+                ))
+            }
+        }
+    }
+
+    /// в виде, в котором таблица представлена в Fractional UCA, без комментариев и пустых строк
+    pub fn formatted(&self) -> String
+    {
+        let mut result = String::new();
+        let mut header = self.header.iter().zip(&self.header_positions).peekable();
+
+        for i in 0 ..= self.entries.len() {
+            while let Some((record, _)) = header.next_if(|(_, &position)| position == i) {
+                result.push_str(record.formatted().as_str());
+                result.push('\n');
+            }
+
+            if let Some(entry) = self.entries.get(i) {
+                result.push_str(entry.formatted().as_str());
+                result.push('\n');
+            }
+        }
+
+        result
+    }
+}
+
+impl FractionalWeightsEntry
+{
+    /// в виде, в котором запись представлена в Fractional UCA
+    pub fn formatted(&self) -> String
+    {
+        let codes: Vec<String> = self.codes.iter().map(|c| format!("{:04X}", c)).collect();
+
+        let codes = match self.ce_case {
+            true => format!("{} | {}", codes[0], codes[1 ..].join(" ")),
+            false => codes.join(" "),
+        };
+
+        let elements: String = self.elements.iter().map(|e| e.formatted()).collect();
+
+        // специальные записи, начинающиеся с нехарактера (FDD0, FDD1, FFFE, FFFF), отделяют веса табуляцией
+        let separator = match self.codes[0] {
+            0xFDD0 ..= 0xFDEF => '\t',
+            code if code & 0xFFFE == 0xFFFE => '\t',
+            _ => ' ',
+        };
+
+        match self.description.is_empty() {
+            true => format!("{};{}{}", codes, separator, elements),
+            false => format!("{};{}{}\t#{}", codes, separator, elements, self.description),
+        }
+    }
+}
+
+impl FractionalElement
+{
+    /// в виде, в котором элемент представлен в Fractional UCA
+    pub fn formatted(&self) -> String
+    {
+        match self {
+            Self::Weights(primary, secondary, tertiary) => {
+                match primary.is_empty() && secondary.is_empty() && tertiary.is_empty() {
+                    true => "[,,]".to_owned(),
+                    false => format!(
+                        "[{}, {}, {}]",
+                        format_bytes(primary),
+                        format_bytes(secondary),
+                        format_bytes(tertiary)
+                    ),
+                }
+            }
+            Self::Han {
+                code,
+                secondary,
+                tertiary,
+            } => {
+                let mut result = format!("[U+{:04X}", code);
+
+                for weight in [secondary, tertiary].into_iter().flatten() {
+                    result.push_str(format!(", {}", format_bytes(weight)).as_str());
+                }
+
+                result.push(']');
+                result
+            }
+        }
+    }
+}
+
+/// байты весов через пробел
+fn format_bytes(bytes: &[u8]) -> String
+{
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub(crate) fn fractional_table(source: &str) -> Result<FractionalUca, ParseError>
+{
+    let mut header = vec![];
+    let mut header_positions = vec![];
+    let mut entries = vec![];
+    let mut lines = vec![];
+
+    for line in Line::iter(SourceFile::FractionalUca, source) {
+        if line.text.is_empty() || line.text.starts_with('#') {
+            continue;
+        }
+
+        if line.text.starts_with('[') {
+            header.push(parse_header(&line)?);
+            header_positions.push(entries.len());
+            continue;
+        }
+
+        let (values, description) = line.text.split_once('#').unwrap_or((line.text, ""));
+        let (codes, elements) = line.split_once(0, values, ";")?;
+
+        let ce_case = codes.contains('|');
+        let codes = line.codes(0, &codes.replace('|', " "))?;
+
+        if codes.is_empty() || (ce_case && codes.len() < 2) {
+            return Err(line.error(0, values, ParseErrorKind::Malformed("некорректные коды")));
+        }
+
+        let elements = elements
+            .trim()
+            .strip_prefix('[')
+            .and_then(|elements| elements.strip_suffix(']'))
+            .ok_or_else(|| line.error(1, elements, ParseErrorKind::Malformed("ожидается [...]")))?
+            .split("][")
+            .map(|element| parse_element(&line, element))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        entries.push(FractionalWeightsEntry {
+            codes,
+            weights: vec![],
+            elements,
+            description: description.to_string(),
+            ce_case,
+        });
+        lines.push(line);
+    }
+
+    let mut table = FractionalUca {
+        han_order: han_order(&header),
+        header,
+        entries: vec![],
+        header_positions,
+    };
+
+    for (mut entry, line) in entries.into_iter().zip(lines) {
+        entry.weights = entry
+            .elements
+            .iter()
+            .map(|element| {
+                table.resolve(element).ok_or_else(|| {
+                    line.error(
+                        1,
+                        &element.formatted(),
+                        ParseErrorKind::Malformed("нет праймари иероглифа"),
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        if entry.weights.len() == 1
+            && entry.weights[0].0.is_empty()
+            && entry.weights[0].1.is_empty()
+            && entry.weights[0].2.is_empty()
+        {
+            entry.weights.clear();
+        }
+
+        table.entries.push(entry);
+    }
+
+    Ok(table)
+}
+
+/// элемент сопоставления: веса или U+xxxx
+fn parse_element(line: &Line, element: &str) -> Result<FractionalElement, ParseError>
+{
+    let levels: Vec<&str> = element.split(',').map(|level| level.trim()).collect();
+
+    if let Some(code) = levels[0].strip_prefix("U+") {
+        let code = line.hex(1, code)?;
+        let weight = |level: &str| line.hex_bytes(1, level);
+
+        return match levels.len() {
+            1 => Ok(FractionalElement::Han {
+                code,
+                secondary: None,
+                tertiary: None,
+            }),
+            2 => Ok(FractionalElement::Han {
+                code,
+                secondary: None,
+                tertiary: Some(weight(levels[1])?),
+            }),
+            3 => Ok(FractionalElement::Han {
+                code,
+                secondary: Some(weight(levels[1])?),
+                tertiary: Some(weight(levels[2])?),
+            }),
+            found => Err(line.error(
+                1,
+                element,
+                ParseErrorKind::FieldsCount { expected: 3, found },
+            )),
+        };
+    }
+
+    if levels.len() != 3 {
+        return Err(line.error(
+            1,
+            element,
+            ParseErrorKind::FieldsCount {
+                expected: 3,
+                found: levels.len(),
+            },
+        ));
+    }
+
+    Ok(FractionalElement::Weights(
+        line.hex_bytes(1, levels[0])?,
+        line.hex_bytes(1, levels[1])?,
+        line.hex_bytes(1, levels[2])?,
+    ))
+}
+
+/// порядок иероглифов: по записям [radical], затем остальные унифицированные иероглифы
+fn han_order(header: &[FractionalUcaHeader]) -> HashMap<u32, u32>
+{
+    let radicals = header.iter().flat_map(|record| match record {
+        FractionalUcaHeader::Radical(radical) => radical.codes.as_slice(),
+        _ => &[],
+    });
+
+    let unified = header.iter().flat_map(|record| match record {
+        FractionalUcaHeader::UnifiedIdeograph(ranges) => ranges.as_slice(),
+        _ => &[],
+    });
+
+    let mut order = HashMap::new();
+
+    for &(first, last) in radicals.chain(unified) {
+        for code in first ..= last {
+            let index = order.len() as u32;
+            order.entry(code).or_insert(index);
+        }
+    }
+
+    order
+}
@@ -0,0 +1,126 @@
+use crate::parse::{Line, ParseError, Weights};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+/// диапазон кодпоинтов с вычисляемыми весами (@implicitweights в allkeys.txt): тангутское письмо,
+/// нюй-шу, киданьское малое письмо
+///
+/// https://www.unicode.org/reports/tr10/#Implicit_Weights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImplicitWeights
+{
+    /// первый кодпоинт диапазона
+    pub first: u32,
+    /// последний кодпоинт диапазона
+    pub last: u32,
+    /// основа праймари первого элемента сопоставления (AAAA)
+    pub base: u16,
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// диапазоны @implicitweights из allkeys.txt
+    pub static ref IMPLICIT_WEIGHTS: &'static Vec<ImplicitWeights> =
+        UnicodeVersion::default().dataset().implicit_weights();
+}
+
+/// вычисляемые элементы сопоставления кодпоинта, отсутствующего в DUCET
+#[cfg(feature = "embedded")]
+pub fn get_implicit_weights(code: u32) -> [Weights; 2]
+{
+    UnicodeVersion::default()
+        .dataset()
+        .implicit_collation_elements(code)
+}
+
+impl Dataset
+{
+    /// вычисляемые элементы сопоставления [.AAAA.0020.0002][.BBBB.0000.0000] кодпоинта,
+    /// отсутствующего в DUCET / CLDR UND, UTS #10, 10.1
+    pub fn implicit_collation_elements(&self, code: u32) -> [Weights; 2]
+    {
+        let ranges = self.implicit_weights();
+
+        let (aaaa, bbbb) = match ranges.iter().find(|r| (r.first ..= r.last).contains(&code)) {
+            // смещение отсчитывается от начала первого диапазона с той же основой
+            Some(range) => {
+                let first = ranges
+                    .iter()
+                    .filter(|r| r.base == range.base)
+                    .map(|r| r.first)
+                    .min()
+                    .unwrap_or(range.first);
+
+                (range.base, (code - first) | 0x8000)
+            }
+            None => {
+                let base = match self.is_unified_ideograph(code) {
+                    true => match code {
+                        0x4E00 ..= 0x9FFF | 0xF900 ..= 0xFAFF => 0xFB40,
+                        _ => 0xFB80,
+                    },
+                    false => 0xFBC0,
+                };
+
+                (base + (code >> 15) as u16, (code & 0x7FFF) | 0x8000)
+            }
+        };
+
+        [
+            Weights {
+                l1: aaaa,
+                l2: 0x0020,
+                l3: 0x0002,
+                is_variable: false,
+            },
+            Weights {
+                l1: bbbb as u16,
+                l2: 0,
+                l3: 0,
+                is_variable: false,
+            },
+        ]
+    }
+
+    /// унифицированный иероглиф (Unified_Ideograph): диапазоны CJK Ideograph в UnicodeData.txt
+    /// и CJK COMPATIBILITY IDEOGRAPH без декомпозиции
+    pub fn is_unified_ideograph(&self, code: u32) -> bool
+    {
+        match self.unicode().get(&code) {
+            Some(codepoint) => {
+                codepoint.name.starts_with("CJK Ideograph")
+                    || (codepoint.name.starts_with("CJK COMPATIBILITY IDEOGRAPH-")
+                        && codepoint.decomposition.is_empty())
+            }
+            None => false,
+        }
+    }
+}
+
+/// разбор строк @implicitweights allkeys.txt
+pub(crate) fn implicit_weights(
+    file: SourceFile,
+    source: &str,
+) -> Result<Vec<ImplicitWeights>, ParseError>
+{
+    let mut ranges = vec![];
+
+    for line in Line::iter(file, source) {
+        let values = match line.text.strip_prefix("@implicitweights") {
+            Some(values) => values.split_once('#').map_or(values, |(values, _)| values),
+            None => continue,
+        };
+
+        let (range, base) = line.split_once(0, values, ";")?;
+        let (first, last) = line.range(0, range)?;
+
+        ranges.push(ImplicitWeights {
+            first,
+            last,
+            base: line.hex_u16(1, base)?,
+        });
+    }
+
+    Ok(ranges)
+}
@@ -1,7 +1,9 @@
 mod allkeys;
-mod fractional_uca;
 mod collation_test;
+mod fractional_uca;
+mod implicit_weights;
 
 pub use allkeys::*;
+pub use collation_test::*;
 pub use fractional_uca::*;
-pub use collation_test::*;
\ No newline at end of file
+pub use implicit_weights::*;
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use crate::codepoint::{BinaryProperty, IndicConjunctBreak};
+use crate::parse::{in_ranges, lookup, property_records, resolve, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// PropList.txt, DerivedCoreProperties.txt, emoji-data.txt: строки вида "диапазон ; свойство",
+// значение бинарных свойств по умолчанию - False. исключение - перечислимое свойство
+// Indic_Conjunct_Break: "диапазон ; InCB; значение", по умолчанию - None
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// бинарные свойства, отсортированные диапазоны
+    pub static ref BINARY_PROPERTIES: &'static HashMap<BinaryProperty, Vec<(u32, u32)>> =
+        UnicodeVersion::default().dataset().binary_properties();
+    /// Indic_Conjunct_Break, отсортированные диапазоны
+    pub static ref INDIC_CONJUNCT_BREAK: &'static Vec<(u32, u32, IndicConjunctBreak)> =
+        UnicodeVersion::default().dataset().incb();
+}
+
+/// обладает ли кодпоинт бинарным свойством
+#[cfg(feature = "embedded")]
+pub fn has_property(code: u32, property: BinaryProperty) -> bool
+{
+    UnicodeVersion::default()
+        .dataset()
+        .has_property(code, property)
+}
+
+/// значение Indic_Conjunct_Break кодпоинта
+#[cfg(feature = "embedded")]
+pub fn get_indic_conjunct_break(code: u32) -> IndicConjunctBreak
+{
+    UnicodeVersion::default()
+        .dataset()
+        .indic_conjunct_break(code)
+}
+
+impl Dataset
+{
+    /// обладает ли кодпоинт бинарным свойством из PropList.txt / DerivedCoreProperties.txt /
+    /// emoji-data.txt
+    pub fn has_property(&self, code: u32, property: BinaryProperty) -> bool
+    {
+        match self.try_has_property(code, property) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// обладает ли кодпоинт бинарным свойством; ошибка - если файла-источника свойства нет
+    /// в наборе данных или его не удалось разобрать
+    pub fn try_has_property(
+        &self,
+        code: u32,
+        property: BinaryProperty,
+    ) -> Result<bool, ParseError>
+    {
+        let file = property.source();
+
+        if !self.has_source(file) {
+            return Err(ParseError::missing_source(file, property.as_str()));
+        }
+
+        Ok(self
+            .try_available_binary_properties()?
+            .get(&property)
+            .is_some_and(|ranges| in_ranges(ranges, code)))
+    }
+
+    /// значение Indic_Conjunct_Break кодпоинта
+    pub fn indic_conjunct_break(&self, code: u32) -> IndicConjunctBreak
+    {
+        lookup(self.incb(), code).copied().unwrap_or_default()
+    }
+}
+
+/// диапазоны кодпоинтов бинарных свойств PropList.txt, DerivedCoreProperties.txt и emoji-data.txt,
+/// упорядоченные
+pub(crate) fn binary_properties(
+    prop_list: &str,
+    derived_core_properties: &str,
+    emoji_data: &str,
+) -> Result<HashMap<BinaryProperty, Vec<(u32, u32)>>, ParseError>
+{
+    let mut properties: HashMap<_, Vec<(u32, u32)>> = HashMap::new();
+
+    let records = property_records(SourceFile::PropList, prop_list)
+        .chain(property_records(
+            SourceFile::DerivedCoreProperties,
+            derived_core_properties,
+        ))
+        .chain(property_records(SourceFile::EmojiData, emoji_data));
+
+    for record in records {
+        let record = record?;
+
+        // значения по умолчанию бинарных свойств - False
+        if record.missing || record.fields.len() != 1 {
+            continue;
+        }
+
+        let name = record.fields[0];
+        let property = record
+            .line
+            .property(1, name, BinaryProperty::try_from(name))?;
+        let range = (*record.range.start(), *record.range.end());
+
+        properties.entry(property).or_default().push(range);
+    }
+
+    for ranges in properties.values_mut() {
+        ranges.sort_unstable();
+    }
+
+    Ok(properties)
+}
+
+/// разбор Indic_Conjunct_Break из DerivedCoreProperties.txt
+pub(crate) fn indic_conjunct_break(
+    source: &str,
+) -> Result<Vec<(u32, u32, IndicConjunctBreak)>, ParseError>
+{
+    let records = property_records(SourceFile::DerivedCoreProperties, source);
+
+    let ranges = resolve(records, |record| {
+        if record.field(0)? != "InCB" {
+            return Ok(None);
+        }
+
+        let value = record.field(1)?;
+
+        record
+            .line
+            .property(2, value, IndicConjunctBreak::try_from(value))
+            .map(Some)
+    })?;
+
+    Ok(ranges
+        .into_iter()
+        .filter(|&(_, _, value)| value != IndicConjunctBreak::None)
+        .collect())
+}
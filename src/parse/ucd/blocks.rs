@@ -1,9 +1,16 @@
 use std::collections::HashMap;
 use std::ops::RangeInclusive;
 
+use crate::parse::{property_records, ParseError, ParseErrorKind};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// блоки кодпоинтов
-    pub static ref CODEPOINTS_BLOCKS: HashMap<String, CodepointsBlock> = blocks();
+    pub static ref CODEPOINTS_BLOCKS: &'static HashMap<String, CodepointsBlock> =
+        UnicodeVersion::default().dataset().blocks();
 }
 
 #[derive(Debug, Clone)]
@@ -23,44 +30,62 @@ impl CodepointsBlock
 }
 
 /// найти блок по его названию
+#[cfg(feature = "embedded")]
 pub fn get_block_by_name(name: &str) -> Option<&'static CodepointsBlock>
 {
-    CODEPOINTS_BLOCKS.get(key(name).as_str())
+    UnicodeVersion::default().dataset().block_by_name(name)
 }
 
 /// найти блок символов, к которому относится кодпоинт
+#[cfg(feature = "embedded")]
 pub fn get_block_by_code(code: u32) -> Option<&'static CodepointsBlock>
 {
-    CODEPOINTS_BLOCKS
-        .values()
-        .into_iter()
-        .find(|block| block.range().contains(&code))
+    UnicodeVersion::default().dataset().block_by_code(code)
 }
 
-const DATA: &str = include_str!("./../../../data/ucd 15.1.0/Blocks.txt");
+impl Dataset
+{
+    /// найти блок по его названию
+    pub fn block_by_name(&self, name: &str) -> Option<&CodepointsBlock>
+    {
+        self.blocks().get(key(name).as_str())
+    }
+
+    /// найти блок символов, к которому относится кодпоинт
+    pub fn block_by_code(&self, code: u32) -> Option<&CodepointsBlock>
+    {
+        self.blocks()
+            .values()
+            .find(|block| block.range().contains(&code))
+    }
+}
 
 /// получение блоков данных Blocks.txt из UCD
-fn blocks() -> HashMap<String, CodepointsBlock>
+pub(crate) fn blocks(source: &str) -> Result<HashMap<String, CodepointsBlock>, ParseError>
 {
     let mut map = HashMap::new();
 
-    DATA.lines()
-        .filter(|line| !line.is_empty() && !line.starts_with('#'))
-        .for_each(|line| {
-            let (range, name) = line.split_once(';').unwrap();
-            let (from, to) = range.split_once("..").unwrap();
+    for record in property_records(SourceFile::Blocks, source) {
+        let record = record?;
 
-            let key = key(name);
-            let name = name.trim().to_string();
-            let from = u32::from_str_radix(from, 16).unwrap();
-            let to = u32::from_str_radix(to, 16).unwrap();
+        // @missing: No_Block - кодпоинт вне блоков
+        if record.missing {
+            continue;
+        }
 
-            assert!(!map.contains_key(&key));
+        let name = record.field(0)?;
+        let key = key(name);
+        let name = name.to_string();
+        let (from, to) = (*record.range.start(), *record.range.end());
 
-            map.insert(key, CodepointsBlock { name, from, to });
-        });
+        if map.contains_key(&key) {
+            return Err(record.error(0, ParseErrorKind::Duplicate));
+        }
+
+        map.insert(key, CodepointsBlock { name, from, to });
+    }
 
-    map
+    Ok(map)
 }
 
 /// согласно Blocks.txt: When comparing block names, casing, whitespace, hyphens, and underbars are ignored.
@@ -0,0 +1,125 @@
+use crate::parse::{Line, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// тесты сегментации UCD (GraphemeBreakTest.txt и т.п.): кодпоинты, разделённые знаками
+// "÷" (граница) и "×" (нет границы), после "#" - описание с номерами применённых правил
+
+/// тест сегментации из UCD
+#[derive(Clone)]
+pub struct BreakTest
+{
+    /// номер строки файла
+    pub line: usize,
+    /// строка теста
+    pub text: String,
+    /// ожидаемые границы - смещения в байтах, включая 0 и длину строки
+    pub boundaries: Vec<usize>,
+    /// описание с номерами правил
+    pub description: String,
+}
+
+impl BreakTest
+{
+    /// ожидаемые сегменты строки
+    pub fn segments(&self) -> Vec<&str>
+    {
+        self.boundaries
+            .windows(2)
+            .map(|pair| &self.text[pair[0] .. pair[1]])
+            .collect()
+    }
+}
+
+impl core::fmt::Debug for BreakTest
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(
+            f,
+            "\n\nLine: {}\n{}\n# {}\n",
+            self.line,
+            breaks(&self.text, &self.boundaries),
+            self.description
+        )
+    }
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// тесты сегментации графем из UCD
+    pub static ref GRAPHEME_BREAK_TESTS: &'static Vec<BreakTest> =
+        UnicodeVersion::default().dataset().grapheme_break_tests();
+    /// тесты границ слов из UCD
+    pub static ref WORD_BREAK_TESTS: &'static Vec<BreakTest> =
+        UnicodeVersion::default().dataset().word_break_tests();
+    /// тесты границ предложений из UCD
+    pub static ref SENTENCE_BREAK_TESTS: &'static Vec<BreakTest> =
+        UnicodeVersion::default().dataset().sentence_break_tests();
+    /// тесты разрыва строк из UCD
+    pub static ref LINE_BREAK_TESTS: &'static Vec<BreakTest> =
+        UnicodeVersion::default().dataset().line_break_tests();
+}
+
+/// строка с границами в формате файлов тестов: "÷ 0020 × 0308 ÷"
+pub(crate) fn breaks(text: &str, boundaries: &[usize]) -> String
+{
+    let mark = |offset: usize| match boundaries.contains(&offset) {
+        true => "÷",
+        false => "×",
+    };
+
+    let mut result = String::from(mark(0));
+
+    for (offset, c) in text.char_indices() {
+        if offset > 0 {
+            result.push(' ');
+            result.push_str(mark(offset));
+        }
+
+        result.push_str(&format!(" {:04X}", u32::from(c)));
+    }
+
+    result.push(' ');
+    result.push_str(mark(text.len()));
+    result
+}
+
+/// разбор теста сегментации из UCD
+pub(crate) fn break_tests(file: SourceFile, source: &str) -> Result<Vec<BreakTest>, ParseError>
+{
+    let mut result = vec![];
+
+    for line in Line::iter(file, source) {
+        let (codes, description) = line.text.split_once('#').unwrap_or((line.text, ""));
+
+        if codes.trim().is_empty() {
+            continue;
+        }
+
+        let mut text = String::new();
+        let mut boundaries = vec![];
+
+        for token in codes.split_whitespace() {
+            match token {
+                "÷" => boundaries.push(text.len()),
+                "×" => (),
+                code => text.push(line.char(0, code)?),
+            }
+        }
+
+        if text.is_empty() {
+            return Err(line.error(0, codes, ParseErrorKind::Malformed("нет кодпоинтов")));
+        }
+
+        result.push(BreakTest {
+            line: line.number,
+            text,
+            boundaries,
+            description: description.trim().to_owned(),
+        });
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::parse::{property_records, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// CaseFolding.txt: "код; статус; отображение; # название", не указанные кодпоинты отображаются
+// в себя. полная свёртка - C + F, простая - C + S, для тюркских языков T заменяет C / F для I и İ
+
+/// статус отображения CaseFolding.txt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseFoldingStatus
+{
+    /// C - общее для простой и полной свёртки
+    Common,
+    /// F - полная свёртка, возможно в несколько кодпоинтов
+    Full,
+    /// S - простая свёртка, если отличается от полной
+    Simple,
+    /// T - тюркские языки, для I и İ
+    Turkic,
+}
+
+/// отображение свёртки регистра
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseFolding
+{
+    pub code: u32,
+    pub status: CaseFoldingStatus,
+    pub mapping: Vec<u32>,
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// отображения свёртки регистра CaseFolding.txt
+    pub static ref CASE_FOLDING: &'static HashMap<u32, Vec<CaseFolding>> =
+        UnicodeVersion::default().dataset().case_folding();
+}
+
+impl CaseFoldingStatus
+{
+    /// обозначение статуса в CaseFolding.txt
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::Common => "C",
+            Self::Full => "F",
+            Self::Simple => "S",
+            Self::Turkic => "T",
+        }
+    }
+}
+
+/// разбор CaseFolding.txt из UCD
+pub(crate) fn case_folding(source: &str) -> Result<HashMap<u32, Vec<CaseFolding>>, ParseError>
+{
+    let mut map: HashMap<u32, Vec<CaseFolding>> = HashMap::new();
+
+    for record in property_records(SourceFile::CaseFolding, source) {
+        let record = record?;
+
+        // @missing: <code point>
+        if record.missing {
+            continue;
+        }
+
+        let status = match record.field(0)? {
+            "C" => CaseFoldingStatus::Common,
+            "F" => CaseFoldingStatus::Full,
+            "S" => CaseFoldingStatus::Simple,
+            "T" => CaseFoldingStatus::Turkic,
+            _ => return Err(record.error(0, ParseErrorKind::Malformed("ожидается C, F, S или T"))),
+        };
+
+        let mapping = record.line.codes(2, record.field(1)?)?;
+
+        for code in record.range.clone() {
+            let foldings = map.entry(code).or_default();
+
+            if foldings.iter().any(|folding| folding.status == status) {
+                return Err(record.error(0, ParseErrorKind::Duplicate));
+            }
+
+            foldings.push(CaseFolding {
+                code,
+                status,
+                mapping: mapping.clone(),
+            });
+        }
+    }
+
+    Ok(map)
+}
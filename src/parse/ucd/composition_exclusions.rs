@@ -1,32 +1,42 @@
+use crate::parse::{property_records, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// исключения композиции
-    pub static ref COMPOSITION_EXCLUSIONS: Vec<u32> = composition_exclusions();
+    pub static ref COMPOSITION_EXCLUSIONS: &'static Vec<u32> =
+        UnicodeVersion::default().dataset().composition_exclusions();
 }
 
-const DATA: &str = include_str!("./../../../data/ucd 15.1.0/CompositionExclusions.txt");
-
 /// разбор CompositionExclusions.txt из UCD
 /// исключения композиции не могут быть вычислены, этот список составляется консорциумом Unicode в ручном режиме
-fn composition_exclusions() -> Vec<u32>
+pub(crate) fn composition_exclusions(source: &str) -> Result<Vec<u32>, ParseError>
 {
     let mut exclusions = vec![];
 
-    for line in DATA.lines() {
-        if line.is_empty() || line.starts_with('#') {
-            continue;
-        }
-
-        let (code, _) = line.split_once('#').unwrap();
-        let code = u32::from_str_radix(code.trim(), 16).unwrap();
-
-        exclusions.push(code);
+    for record in property_records(SourceFile::CompositionExclusions, source) {
+        exclusions.extend(record?.range);
     }
 
-    exclusions
+    Ok(exclusions)
 }
 
 /// является ли кодпоинт исключением композиции?
+#[cfg(feature = "embedded")]
 pub fn is_composition_exclusion(code: u32) -> bool
 {
-    COMPOSITION_EXCLUSIONS.contains(&code)
+    UnicodeVersion::default()
+        .dataset()
+        .is_composition_exclusion(code)
+}
+
+impl Dataset
+{
+    /// является ли кодпоинт исключением композиции?
+    pub fn is_composition_exclusion(&self, code: u32) -> bool
+    {
+        self.composition_exclusions().contains(&code)
+    }
 }
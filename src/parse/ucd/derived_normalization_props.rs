@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use crate::codepoint::PropertiesError;
+use crate::parse::{in_ranges, property_records, ParseError, ParseErrorKind};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// DerivedNormalizationProps.txt: строки вида "диапазон ; свойство [; значение] # комментарий",
+// для бинарных свойств значение отсутствует, для отображений (NFKC_CF, NFKC_SCF, FC_NFKC) -
+// кодпоинты результата, возможно пустые; отсутствующие в отображении кодпоинты отображаются в себя.
+// быстрые проверки (*_QC) разбираются отдельно, смотри quick_checks.rs
+
+/// бинарное свойство из DerivedNormalizationProps.txt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationProperty
+{
+    /// исключения композиции, синглтоны и декомпозиции, начинающиеся с нестартера
+    FullCompositionExclusion,
+    /// длина NFD-формы кодпоинта не равна 1 (устаревшее)
+    ExpandsOnNfd,
+    /// длина NFC-формы кодпоинта не равна 1 (устаревшее)
+    ExpandsOnNfc,
+    /// длина NFKD-формы кодпоинта не равна 1 (устаревшее)
+    ExpandsOnNfkd,
+    /// длина NFKC-формы кодпоинта не равна 1 (устаревшее)
+    ExpandsOnNfkc,
+    /// кодпоинт изменяется при NFKC_Casefold
+    ChangesWhenNfkcCasefolded,
+}
+
+impl NormalizationProperty
+{
+    /// все свойства
+    pub const ALL: [Self; 6] = [
+        Self::FullCompositionExclusion,
+        Self::ExpandsOnNfd,
+        Self::ExpandsOnNfc,
+        Self::ExpandsOnNfkd,
+        Self::ExpandsOnNfkc,
+        Self::ChangesWhenNfkcCasefolded,
+    ];
+
+    /// название свойства в UCD
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::FullCompositionExclusion => "Full_Composition_Exclusion",
+            Self::ExpandsOnNfd => "Expands_On_NFD",
+            Self::ExpandsOnNfc => "Expands_On_NFC",
+            Self::ExpandsOnNfkd => "Expands_On_NFKD",
+            Self::ExpandsOnNfkc => "Expands_On_NFKC",
+            Self::ChangesWhenNfkcCasefolded => "Changes_When_NFKC_Casefolded",
+        }
+    }
+
+    /// свойство по названию в UCD
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        Self::ALL
+            .into_iter()
+            .find(|property| property.as_str() == name)
+    }
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// NFKC_Casefold (NFKC_CF)
+    pub static ref NFKC_CF: &'static HashMap<u32, Vec<u32>> =
+        UnicodeVersion::default().dataset().nfkc_cf();
+    /// NFKC_Simple_Casefold (NFKC_SCF)
+    pub static ref NFKC_SCF: &'static HashMap<u32, Vec<u32>> =
+        UnicodeVersion::default().dataset().nfkc_scf();
+    /// FC_NFKC_Closure (устаревшее)
+    pub static ref FC_NFKC: &'static HashMap<u32, Vec<u32>> =
+        UnicodeVersion::default().dataset().fc_nfkc();
+    /// бинарные свойства, отсортированные диапазоны
+    pub static ref NORMALIZATION_PROPERTIES:
+        &'static HashMap<NormalizationProperty, Vec<(u32, u32)>> =
+        UnicodeVersion::default().dataset().normalization_properties();
+}
+
+/// является ли кодпоинт полным исключением композиции (Full_Composition_Exclusion)
+#[cfg(feature = "embedded")]
+pub fn is_full_composition_exclusion(code: u32) -> bool
+{
+    UnicodeVersion::default()
+        .dataset()
+        .is_full_composition_exclusion(code)
+}
+
+impl Dataset
+{
+    /// обладает ли кодпоинт бинарным свойством из DerivedNormalizationProps.txt
+    pub fn has_normalization_property(&self, code: u32, property: NormalizationProperty) -> bool
+    {
+        self.normalization_properties()
+            .get(&property)
+            .is_some_and(|ranges| in_ranges(ranges, code))
+    }
+
+    /// является ли кодпоинт полным исключением композиции (Full_Composition_Exclusion)
+    pub fn is_full_composition_exclusion(&self, code: u32) -> bool
+    {
+        self.has_normalization_property(code, NormalizationProperty::FullCompositionExclusion)
+    }
+}
+
+/// диапазоны кодпоинтов бинарных свойств, упорядоченные
+pub(crate) fn derived_properties(
+    source: &str,
+) -> Result<HashMap<NormalizationProperty, Vec<(u32, u32)>>, ParseError>
+{
+    let mut properties: HashMap<_, Vec<(u32, u32)>> = NormalizationProperty::ALL
+        .into_iter()
+        .map(|property| (property, vec![]))
+        .collect();
+
+    for record in property_records(SourceFile::DerivedNormalizationProps, source) {
+        let record = record?;
+
+        // значения по умолчанию бинарных свойств - False
+        if record.missing || record.fields.len() != 1 {
+            continue;
+        }
+
+        let name = record.fields[0];
+        let property = record.line.property(
+            1,
+            name,
+            NormalizationProperty::from_name(name)
+                .ok_or_else(|| PropertiesError::unknown("Normalization_Property", name)),
+        )?;
+        let range = (*record.range.start(), *record.range.end());
+
+        properties.entry(property).or_default().push(range);
+    }
+
+    for ranges in properties.values_mut() {
+        ranges.sort_unstable();
+    }
+
+    Ok(properties)
+}
+
+/// отображение кодпоинтов в последовательности кодпоинтов
+pub(crate) fn derived_mapping(
+    source: &str,
+    property: &str,
+) -> Result<HashMap<u32, Vec<u32>>, ParseError>
+{
+    let mut mapping = HashMap::new();
+
+    for record in property_records(SourceFile::DerivedNormalizationProps, source) {
+        let record = record?;
+
+        // @missing: <code point> - кодпоинт отображается в себя
+        if record.missing || record.field(0)? != property {
+            continue;
+        }
+
+        let value = record.fields.get(1).copied().unwrap_or_default();
+        let codes = record.line.codes(2, value)?;
+
+        for code in record.range.clone() {
+            if mapping.insert(code, codes.clone()).is_some() {
+                return Err(record
+                    .line
+                    .error(0, record.line.text, ParseErrorKind::Duplicate));
+            }
+        }
+    }
+
+    Ok(mapping)
+}
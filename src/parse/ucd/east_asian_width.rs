@@ -0,0 +1,52 @@
+use crate::codepoint::EastAsianWidth;
+use crate::parse::{lookup, property_records, resolve, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// EastAsianWidth.txt: "диапазон ; значение # комментарий", по умолчанию - N, для иероглифов
+// (@missing) - W
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// East_Asian_Width, отсортированные диапазоны
+    pub static ref EAST_ASIAN_WIDTH: &'static Vec<(u32, u32, EastAsianWidth)> =
+        UnicodeVersion::default().dataset().ea();
+}
+
+/// значение East_Asian_Width кодпоинта
+#[cfg(feature = "embedded")]
+pub fn get_east_asian_width(code: u32) -> EastAsianWidth
+{
+    UnicodeVersion::default().dataset().east_asian_width(code)
+}
+
+impl Dataset
+{
+    /// значение East_Asian_Width кодпоинта
+    pub fn east_asian_width(&self, code: u32) -> EastAsianWidth
+    {
+        lookup(self.ea(), code).copied().unwrap_or_default()
+    }
+}
+
+/// разбор EastAsianWidth.txt из UCD
+pub(crate) fn east_asian_width(source: &str)
+    -> Result<Vec<(u32, u32, EastAsianWidth)>, ParseError>
+{
+    let records = property_records(SourceFile::EastAsianWidth, source);
+
+    let ranges = resolve(records, |record| {
+        let value = record.field(0)?;
+
+        record
+            .line
+            .property(1, value, EastAsianWidth::try_from(value))
+            .map(Some)
+    })?;
+
+    Ok(ranges
+        .into_iter()
+        .filter(|&(_, _, value)| value != EastAsianWidth::Neutral)
+        .collect())
+}
@@ -0,0 +1,54 @@
+use crate::codepoint::GraphemeClusterBreak;
+use crate::parse::{lookup, property_records, resolve, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// GraphemeBreakProperty.txt: "диапазон ; значение # комментарий", по умолчанию - Other
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// Grapheme_Cluster_Break, отсортированные диапазоны
+    pub static ref GRAPHEME_CLUSTER_BREAK: &'static Vec<(u32, u32, GraphemeClusterBreak)> =
+        UnicodeVersion::default().dataset().gcb();
+}
+
+/// значение Grapheme_Cluster_Break кодпоинта
+#[cfg(feature = "embedded")]
+pub fn get_grapheme_cluster_break(code: u32) -> GraphemeClusterBreak
+{
+    UnicodeVersion::default()
+        .dataset()
+        .grapheme_cluster_break(code)
+}
+
+impl Dataset
+{
+    /// значение Grapheme_Cluster_Break кодпоинта
+    pub fn grapheme_cluster_break(&self, code: u32) -> GraphemeClusterBreak
+    {
+        lookup(self.gcb(), code).copied().unwrap_or_default()
+    }
+}
+
+/// разбор GraphemeBreakProperty.txt из UCD
+pub(crate) fn grapheme_cluster_break(
+    source: &str,
+) -> Result<Vec<(u32, u32, GraphemeClusterBreak)>, ParseError>
+{
+    let records = property_records(SourceFile::GraphemeBreakProperty, source);
+
+    let ranges = resolve(records, |record| {
+        let value = record.field(0)?;
+
+        record
+            .line
+            .property(1, value, GraphemeClusterBreak::try_from(value))
+            .map(Some)
+    })?;
+
+    Ok(ranges
+        .into_iter()
+        .filter(|&(_, _, value)| value != GraphemeClusterBreak::Other)
+        .collect())
+}
@@ -0,0 +1,51 @@
+use crate::codepoint::LineBreak;
+use crate::parse::{lookup, property_records, resolve, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// LineBreak.txt: "диапазон ; значение # комментарий", по умолчанию - XX, для иероглифов и
+// пиктограмм (@missing) - ID, для знаков валют - PR
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// Line_Break, отсортированные диапазоны
+    pub static ref LINE_BREAK: &'static Vec<(u32, u32, LineBreak)> =
+        UnicodeVersion::default().dataset().lb();
+}
+
+/// значение Line_Break кодпоинта
+#[cfg(feature = "embedded")]
+pub fn get_line_break(code: u32) -> LineBreak
+{
+    UnicodeVersion::default().dataset().line_break(code)
+}
+
+impl Dataset
+{
+    /// значение Line_Break кодпоинта без разрешения по правилу LB1
+    pub fn line_break(&self, code: u32) -> LineBreak
+    {
+        lookup(self.lb(), code).copied().unwrap_or_default()
+    }
+}
+
+/// разбор LineBreak.txt из UCD
+pub(crate) fn line_break(source: &str) -> Result<Vec<(u32, u32, LineBreak)>, ParseError>
+{
+    let records = property_records(SourceFile::LineBreak, source);
+
+    let ranges = resolve(records, |record| {
+        let value = record.field(0)?;
+
+        record
+            .line
+            .property(1, value, LineBreak::try_from(value))
+            .map(Some)
+    })?;
+
+    Ok(ranges
+        .into_iter()
+        .filter(|&(_, _, value)| value != LineBreak::Unknown)
+        .collect())
+}
@@ -1,11 +1,59 @@
+mod binary_properties;
 mod blocks;
+mod break_tests;
+mod case_folding;
 mod composition_exclusions;
+mod derived_normalization_props;
+mod east_asian_width;
+mod grapheme_break;
+mod line_break;
 mod normalization_tests;
+mod property_file;
 mod quick_checks;
+mod scripts;
+mod sentence_break;
+mod special_casing;
 mod unicode;
+mod word_break;
 
+#[cfg(feature = "embedded")]
+pub use binary_properties::*;
 pub use blocks::*;
+pub use break_tests::*;
+pub use case_folding::*;
+#[cfg(feature = "embedded")]
 pub use composition_exclusions::*;
+pub use derived_normalization_props::*;
+#[cfg(feature = "embedded")]
+pub use east_asian_width::*;
+#[cfg(feature = "embedded")]
+pub use grapheme_break::*;
+#[cfg(feature = "embedded")]
+pub use line_break::*;
 pub use normalization_tests::*;
+pub use property_file::property_file_records;
 pub use quick_checks::*;
+#[cfg(feature = "embedded")]
+pub use scripts::*;
+#[cfg(feature = "embedded")]
+pub use sentence_break::*;
+pub use special_casing::*;
+#[cfg(feature = "embedded")]
 pub use unicode::UNICODE;
+#[cfg(feature = "embedded")]
+pub use word_break::*;
+
+pub(crate) use binary_properties::{binary_properties, indic_conjunct_break};
+pub(crate) use break_tests::{break_tests, breaks};
+pub(crate) use case_folding::case_folding;
+pub(crate) use composition_exclusions::composition_exclusions;
+pub(crate) use derived_normalization_props::{derived_mapping, derived_properties};
+pub(crate) use east_asian_width::east_asian_width;
+pub(crate) use grapheme_break::grapheme_cluster_break;
+pub(crate) use line_break::line_break;
+pub(crate) use property_file::*;
+pub(crate) use scripts::{script_extensions, scripts};
+pub(crate) use sentence_break::sentence_break;
+pub(crate) use special_casing::special_casing;
+pub(crate) use unicode::unicode;
+pub(crate) use word_break::word_break;
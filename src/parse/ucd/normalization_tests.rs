@@ -1,3 +1,8 @@
+use crate::parse::{Line, ParseError, ParseErrorKind};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
 /// тест из UCD
 #[derive(Clone)]
 pub struct NormalizationTest
@@ -41,65 +46,77 @@ impl core::fmt::Debug for NormalizationTest
     }
 }
 
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// тесты нормализации из UCD
-    pub static ref NORMALIZATION_TESTS: Vec<NormalizationTest> = normalization_tests();
+    pub static ref NORMALIZATION_TESTS: &'static Vec<NormalizationTest> =
+        UnicodeVersion::default().dataset().normalization_tests();
 }
 
-const DATA: &str = include_str!("./../../../data/ucd 15.1.0/NormalizationTest.txt");
-
 /// получить тест по номеру строки
+#[cfg(feature = "embedded")]
 pub fn get_normalization_test(i: usize) -> Option<&'static NormalizationTest>
 {
-    NORMALIZATION_TESTS.iter().find(|t| t.line == i)
+    UnicodeVersion::default().dataset().normalization_test(i)
+}
+
+impl Dataset
+{
+    /// получить тест по номеру строки
+    pub fn normalization_test(&self, i: usize) -> Option<&NormalizationTest>
+    {
+        self.normalization_tests().iter().find(|t| t.line == i)
+    }
 }
 
 /// разбор NormalizationTest.txt из UCD
-fn normalization_tests() -> Vec<NormalizationTest>
+pub(crate) fn normalization_tests(source: &str) -> Result<Vec<NormalizationTest>, ParseError>
 {
     let mut result = vec![];
     let mut part = String::new();
 
-    for (i, line) in DATA.lines().enumerate() {
-        if line.starts_with('#') {
+    for line in Line::iter(SourceFile::NormalizationTest, source) {
+        if line.text.is_empty() || line.text.starts_with('#') {
             continue;
         }
 
-        if line.starts_with('@') {
-            part = line.to_owned();
+        if line.text.starts_with('@') {
+            part = line.text.to_owned();
             continue;
         }
 
-        let (codes, description) = line.split_once('#').unwrap();
+        let (codes, description) = line.split_once(0, line.text, "#")?;
         let codes: Vec<&str> = codes.split(';').collect();
 
         if codes.len() != 6 {
-            panic!("{}: некорректное количество полей теста", i);
+            return Err(line.error(
+                0,
+                line.text,
+                ParseErrorKind::FieldsCount {
+                    expected: 6,
+                    found: codes.len(),
+                },
+            ));
         }
 
-        macro_rules! codes {
-            ($str: expr) => {{
-                $str.split_whitespace()
-                    .collect::<Vec<&str>>()
-                    .iter()
-                    .map(|v| unsafe {
-                        char::from_u32_unchecked(u32::from_str_radix(v, 16).unwrap())
-                    })
-                    .collect()
-            }};
-        }
+        let column = |field: usize| -> Result<String, ParseError> {
+            codes[field]
+                .split_whitespace()
+                .map(|code| line.char(field, code))
+                .collect()
+        };
 
         result.push(NormalizationTest {
             part: part.clone(),
             description: description.to_owned(),
-            line: i + 1,
-            c1: codes!(codes[0]),
-            c2: codes!(codes[1]),
-            c3: codes!(codes[2]),
-            c4: codes!(codes[3]),
-            c5: codes!(codes[4]),
+            line: line.number,
+            c1: column(0)?,
+            c2: column(1)?,
+            c3: column(2)?,
+            c4: column(3)?,
+            c5: column(4)?,
         })
     }
 
-    result
+    Ok(result)
 }
@@ -0,0 +1,268 @@
+use std::ops::RangeInclusive;
+
+use crate::parse::{Line, ParseError, ParseErrorKind};
+use crate::SourceFile;
+
+// общий формат файлов свойств UCD (UAX #44, 4.2): строки "диапазон ; поле ; ... # комментарий",
+// диапазон - XXXX..YYYY или одиночный кодпоинт. строки "# @missing: диапазон ; поле ; ..." задают
+// значения по умолчанию для кодпоинтов, не перечисленных в файле явно; если строки @missing
+// пересекаются - действует более поздняя.
+
+/// запись файла свойств UCD
+#[derive(Clone)]
+pub(crate) struct PropertyRecord<'a>
+{
+    /// строка файла - для построения ошибок
+    pub line: Line<'a>,
+    /// диапазон кодпоинтов
+    pub range: RangeInclusive<u32>,
+    /// поля, следующие за диапазоном, без пробелов по краям
+    pub fields: Vec<&'a str>,
+    /// значение по умолчанию (строка @missing)
+    pub missing: bool,
+}
+
+impl<'a> PropertyRecord<'a>
+{
+    /// поле записи по индексу (0 - первое поле после диапазона)
+    pub fn field(&self, index: usize) -> Result<&'a str, ParseError>
+    {
+        self.fields.get(index).copied().ok_or_else(|| {
+            self.line.error(
+                index + 1,
+                self.line.text,
+                ParseErrorKind::FieldsCount {
+                    expected: index + 1,
+                    found: self.fields.len(),
+                },
+            )
+        })
+    }
+
+    /// ошибка в поле записи по индексу (0 - первое поле после диапазона)
+    pub fn error(&self, index: usize, kind: ParseErrorKind) -> ParseError
+    {
+        let text = self.fields.get(index).copied().unwrap_or(self.line.text);
+
+        self.line.error(index + 1, text, kind)
+    }
+}
+
+/// записи файла свойств UCD, включая строки @missing
+pub(crate) fn property_records<'a>(
+    file: SourceFile,
+    source: &'a str,
+) -> impl Iterator<Item = Result<PropertyRecord<'a>, ParseError>>
+{
+    Line::iter(file, source).filter_map(|line| {
+        let (text, missing) = match line.text.trim_start().strip_prefix('#') {
+            Some(comment) => match comment.trim_start().strip_prefix("@missing:") {
+                Some(text) => (text, true),
+                None => return None,
+            },
+            None => (
+                line.text
+                    .split_once('#')
+                    .map_or(line.text, |(text, _)| text),
+                false,
+            ),
+        };
+
+        if text.trim().is_empty() {
+            return None;
+        }
+
+        Some(record(line, text, missing))
+    })
+}
+
+/// записи файла свойств UCD (UAX #44, 4.2) в виде (диапазон, поля после диапазона): сначала -
+/// записи, перечисленные явно, в порядке следования в файле, затем - значения по умолчанию из
+/// строк @missing для диапазонов, не перечисленных явно, по возрастанию кодпоинтов
+///
+/// последнее поле строки @missing - значение, предшествующие - свойство, к которому оно относится:
+/// "# @missing: 0000..10FFFF; Unknown" действует на все записи файла, "# @missing: 0000..10FFFF;
+/// InCB; None" - только на записи, первое поле которых - InCB. если строки @missing одного
+/// свойства пересекаются - действует более поздняя
+pub fn property_file_records(
+    file: SourceFile,
+    source: &str,
+) -> impl Iterator<Item = Result<(RangeInclusive<u32>, Vec<&str>), ParseError>>
+{
+    let records = match property_records(file, source).collect::<Result<Vec<_>, _>>() {
+        Ok(records) => records,
+        Err(error) => return vec![Err(error)].into_iter(),
+    };
+
+    let (missing, explicit): (Vec<_>, Vec<_>) = records.into_iter().partition(|r| r.missing);
+
+    // поля, определяющие свойство, к которому относится значение по умолчанию
+    let property = |record: &PropertyRecord| record.fields.len().saturating_sub(1);
+
+    let mut defaults = vec![];
+
+    for (i, record) in missing.iter().enumerate().rev() {
+        let fields = &record.fields[.. property(record)];
+
+        // диапазоны, перечисленные явно, и диапазоны более поздних строк @missing того же свойства
+        let explicit = explicit
+            .iter()
+            .filter(|other| other.fields.starts_with(fields));
+        let later = missing[i + 1 ..]
+            .iter()
+            .filter(|other| other.fields[.. property(other)] == *fields);
+
+        let mut covered: Vec<(u32, u32)> = explicit
+            .chain(later)
+            .map(|other| (*other.range.start(), *other.range.end()))
+            .collect();
+
+        covered.sort_unstable();
+
+        let mut next = *record.range.start();
+
+        for (first, last) in covered {
+            if last < next || first > *record.range.end() {
+                continue;
+            }
+
+            if first > next {
+                defaults.push((next ..= first - 1, record.fields.clone()));
+            }
+
+            next = last + 1;
+        }
+
+        if next <= *record.range.end() {
+            defaults.push((next ..= *record.range.end(), record.fields.clone()));
+        }
+    }
+
+    defaults.sort_by_key(|(range, _)| *range.start());
+
+    explicit
+        .into_iter()
+        .map(|record| (record.range, record.fields))
+        .chain(defaults)
+        .map(Ok)
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+/// разбор записи
+fn record<'a>(
+    line: Line<'a>,
+    text: &'a str,
+    missing: bool,
+) -> Result<PropertyRecord<'a>, ParseError>
+{
+    let mut fields = text.split(';');
+    let range = fields.next().unwrap_or_default();
+
+    let (first, last) = line.range(0, range)?;
+
+    Ok(PropertyRecord {
+        line,
+        range: first ..= last,
+        fields: fields.map(str::trim).collect(),
+        missing,
+    })
+}
+
+/// значения свойства по диапазонам кодпоинтов с учётом строк @missing: упорядоченные
+/// непересекающиеся диапазоны (первый, последний, значение), соседние с одинаковым значением
+/// объединены; value возвращает None для записей, не относящихся к свойству
+pub(crate) fn resolve<'a, T, F>(
+    records: impl Iterator<Item = Result<PropertyRecord<'a>, ParseError>>,
+    mut value: F,
+) -> Result<Vec<(u32, u32, T)>, ParseError>
+where
+    T: Clone + PartialEq,
+    F: FnMut(&PropertyRecord<'a>) -> Result<Option<T>, ParseError>,
+{
+    let mut explicit = vec![];
+    let mut missing = vec![];
+
+    for record in records {
+        let record = record?;
+
+        if let Some(value) = value(&record)? {
+            let range = (*record.range.start(), *record.range.end(), value);
+
+            match record.missing {
+                true => missing.push(range),
+                false => explicit.push((range, record.line)),
+            }
+        }
+    }
+
+    explicit.sort_by_key(|&((first, _, _), _)| first);
+
+    for pair in explicit.windows(2) {
+        let ((_, last, _), _) = &pair[0];
+        let ((first, _, _), line) = &pair[1];
+
+        if first <= last {
+            return Err(line.error(0, line.text, ParseErrorKind::Duplicate));
+        }
+    }
+
+    let explicit: Vec<(u32, u32, T)> = explicit.into_iter().map(|(range, _)| range).collect();
+
+    let mut bounds: Vec<u32> = explicit
+        .iter()
+        .chain(missing.iter())
+        .flat_map(|&(first, last, _)| [first, last + 1])
+        .collect();
+
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let mut ranges: Vec<(u32, u32, T)> = vec![];
+
+    for pair in bounds.windows(2) {
+        let (first, last) = (pair[0], pair[1] - 1);
+
+        let index = explicit.partition_point(|&(_, last, _)| last < first);
+
+        let found = match explicit.get(index) {
+            Some((start, _, value)) if *start <= first => Some(value),
+            _ => missing
+                .iter()
+                .rev()
+                .find(|&&(start, end, _)| start <= first && first <= end)
+                .map(|(_, _, value)| value),
+        };
+
+        let value = match found {
+            Some(value) => value,
+            None => continue,
+        };
+
+        match ranges.last_mut() {
+            Some(previous) if previous.1 + 1 == first && previous.2 == *value => previous.1 = last,
+            _ => ranges.push((first, last, value.clone())),
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// значение кодпоинта в упорядоченных непересекающихся диапазонах
+pub(crate) fn lookup<T>(ranges: &[(u32, u32, T)], code: u32) -> Option<&T>
+{
+    let index = ranges.partition_point(|&(_, last, _)| last < code);
+
+    match ranges.get(index) {
+        Some((first, _, value)) if *first <= code => Some(value),
+        _ => None,
+    }
+}
+
+/// входит ли кодпоинт в упорядоченные непересекающиеся диапазоны
+pub(crate) fn in_ranges(ranges: &[(u32, u32)], code: u32) -> bool
+{
+    let index = ranges.partition_point(|&(_, last)| last < code);
+
+    ranges.get(index).is_some_and(|&(first, _)| first <= code)
+}
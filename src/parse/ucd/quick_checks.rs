@@ -1,80 +1,102 @@
-lazy_static! {
-    pub static ref QC_NFD: Vec<char> = nfd_qc();
-    pub static ref QC_NFC: Vec<char> = nfc_qc();
-    pub static ref QC_NFKD: Vec<char> = nfkd_qc();
-    pub static ref QC_NFKC: Vec<char> = nfkc_qc();
+use crate::parse::{lookup, property_records, resolve, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+/// значение быстрой проверки нормализации (NF*_Quick_Check)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuickCheck
+{
+    /// кодпоинт (строка) точно в форме нормализации
+    #[default]
+    Yes,
+    /// кодпоинт (строка) точно не в форме нормализации
+    No,
+    /// требуется полная проверка - только NFC / NFKC
+    Maybe,
 }
 
-const DATA: &str = include_str!("./../../../data/ucd 15.1.0/DerivedNormalizationProps.txt");
-
-const QC_TABLE_SIZE: usize = 0x2FA1D + 1;
+/// таблица быстрых проверок: отсортированные непересекающиеся диапазоны со значениями No / Maybe,
+/// для остальных кодпоинтов - Yes
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct QuickCheckTable
+{
+    ranges: Vec<(u32, u32, QuickCheck)>,
+}
 
-/// быстрые проверки NFD, Y/N
-fn nfd_qc() -> Vec<char>
+impl QuickCheckTable
 {
-    let mut table = vec!['Y'; QC_TABLE_SIZE];
+    /// значение быстрой проверки кодпоинта
+    pub fn get(&self, code: u32) -> QuickCheck
+    {
+        lookup(&self.ranges, code).copied().unwrap_or_default()
+    }
+
+    /// диапазоны кодпоинтов (первый, последний, значение) со значением, отличным от Yes
+    pub fn ranges(&self) -> &[(u32, u32, QuickCheck)]
+    {
+        &self.ranges
+    }
+}
 
-    fill_table("NFD_Quick_Check=No", 'N', &mut table);
+#[cfg(feature = "embedded")]
+lazy_static! {
+    pub static ref QC_NFD: &'static QuickCheckTable = UnicodeVersion::default().dataset().qc_nfd();
+    pub static ref QC_NFC: &'static QuickCheckTable = UnicodeVersion::default().dataset().qc_nfc();
+    pub static ref QC_NFKD: &'static QuickCheckTable =
+        UnicodeVersion::default().dataset().qc_nfkd();
+    pub static ref QC_NFKC: &'static QuickCheckTable =
+        UnicodeVersion::default().dataset().qc_nfkc();
+}
 
-    table
+/// быстрые проверки NFD, Y/N
+pub(crate) fn nfd_qc(source: &str) -> Result<QuickCheckTable, ParseError>
+{
+    quick_check_table(source, "NFD_QC")
 }
 
 /// быстрые проверки NFC Y/N/M
-fn nfc_qc() -> Vec<char>
+pub(crate) fn nfc_qc(source: &str) -> Result<QuickCheckTable, ParseError>
 {
-    let mut table = vec!['Y'; QC_TABLE_SIZE];
-
-    fill_table("NFC_Quick_Check=No", 'N', &mut table);
-    fill_table("NFC_Quick_Check=Maybe", 'M', &mut table);
-
-    table
+    quick_check_table(source, "NFC_QC")
 }
 
 /// быстрые проверки NFKD Y/N
-fn nfkd_qc() -> Vec<char>
+pub(crate) fn nfkd_qc(source: &str) -> Result<QuickCheckTable, ParseError>
 {
-    let mut table = vec!['Y'; QC_TABLE_SIZE];
-    fill_table("NFKD_Quick_Check=No", 'N', &mut table);
-
-    table
+    quick_check_table(source, "NFKD_QC")
 }
 
 /// быстрые проверки NFKC Y/N/M
-fn nfkc_qc() -> Vec<char>
+pub(crate) fn nfkc_qc(source: &str) -> Result<QuickCheckTable, ParseError>
 {
-    let mut table = vec!['Y'; QC_TABLE_SIZE];
-
-    fill_table("NFKC_Quick_Check=No", 'N', &mut table);
-    fill_table("NFKC_Quick_Check=Maybe", 'M', &mut table);
-    table
+    quick_check_table(source, "NFKC_QC")
 }
 
-fn fill_table(block: &str, value: char, table: &mut Vec<char>)
+/// таблица быстрой проверки по короткому названию свойства, с учётом @missing
+fn quick_check_table(source: &str, property: &str) -> Result<QuickCheckTable, ParseError>
 {
-    let lines = DATA
-        .lines()
-        .skip_while(|&line| !line.contains(block))
-        .skip(2);
+    let records = property_records(SourceFile::DerivedNormalizationProps, source);
 
-    for line in lines {
-        if line.is_empty() || line.starts_with('#') {
-            break;
+    let ranges = resolve(records, |record| {
+        if record.field(0)? != property {
+            return Ok(None);
         }
 
-        let (codepoints, _) = line.split_once(';').unwrap();
+        let value = match record.field(1)? {
+            "Y" | "Yes" => QuickCheck::Yes,
+            "N" | "No" => QuickCheck::No,
+            "M" | "Maybe" => QuickCheck::Maybe,
+            _ => return Err(record.error(1, ParseErrorKind::Malformed("ожидается Y, N или M"))),
+        };
 
-        if codepoints.contains("..") {
-            let (from, to) = codepoints.trim().split_once("..").unwrap();
-            let from = usize::from_str_radix(from, 16).unwrap();
-            let to = usize::from_str_radix(to, 16).unwrap();
+        Ok(Some(value))
+    })?;
 
-            for code in from ..= to {
-                table[code] = value;
-            }
-        } else {
-            let code = usize::from_str_radix(codepoints.trim(), 16).unwrap();
+    let ranges = ranges
+        .into_iter()
+        .filter(|&(_, _, value)| value != QuickCheck::Yes)
+        .collect();
 
-            table[code] = value;
-        }
-    }
+    Ok(QuickCheckTable { ranges })
 }
@@ -0,0 +1,88 @@
+use crate::codepoint::Script;
+use crate::parse::{lookup, property_records, resolve, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// Scripts.txt: "диапазон ; длинное название письменности", по умолчанию - Unknown.
+// ScriptExtensions.txt: "диапазон ; короткие названия через пробел", по умолчанию - значение Script
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// письменности кодпоинтов, упорядоченные диапазоны
+    pub static ref SCRIPTS: &'static Vec<(u32, u32, Script)> =
+        UnicodeVersion::default().dataset().scripts();
+    /// расширения письменностей (Script_Extensions), упорядоченные диапазоны
+    pub static ref SCRIPT_EXTENSIONS: &'static Vec<(u32, u32, Vec<Script>)> =
+        UnicodeVersion::default().dataset().scx();
+}
+
+/// письменность кодпоинта
+#[cfg(feature = "embedded")]
+pub fn get_script(code: u32) -> Script
+{
+    UnicodeVersion::default().dataset().script(code)
+}
+
+/// письменности, в которых используется кодпоинт (Script_Extensions)
+#[cfg(feature = "embedded")]
+pub fn get_script_extensions(code: u32) -> Vec<Script>
+{
+    UnicodeVersion::default().dataset().script_extensions(code)
+}
+
+impl Dataset
+{
+    /// письменность кодпоинта, Unknown - если кодпоинт не указан в Scripts.txt
+    pub fn script(&self, code: u32) -> Script
+    {
+        lookup(self.scripts(), code)
+            .copied()
+            .unwrap_or(Script::Unknown)
+    }
+
+    /// письменности, в которых используется кодпоинт (Script_Extensions); для кодпоинтов,
+    /// не указанных в ScriptExtensions.txt, - письменность кодпоинта
+    pub fn script_extensions(&self, code: u32) -> Vec<Script>
+    {
+        match lookup(self.scx(), code) {
+            Some(scripts) => scripts.clone(),
+            None => vec![self.script(code)],
+        }
+    }
+}
+
+/// разбор Scripts.txt из UCD
+pub(crate) fn scripts(source: &str) -> Result<Vec<(u32, u32, Script)>, ParseError>
+{
+    resolve(property_records(SourceFile::Scripts, source), |record| {
+        let name = record.field(0)?;
+
+        record
+            .line
+            .property(1, name, Script::try_from(name))
+            .map(Some)
+    })
+}
+
+/// разбор ScriptExtensions.txt из UCD
+pub(crate) fn script_extensions(source: &str) -> Result<Vec<(u32, u32, Vec<Script>)>, ParseError>
+{
+    resolve(
+        property_records(SourceFile::ScriptExtensions, source),
+        |record| {
+            let names = record.field(0)?;
+
+            // @missing: <script> - значение свойства Script
+            if names.starts_with('<') {
+                return Ok(None);
+            }
+
+            names
+                .split_whitespace()
+                .map(|name| record.line.property(1, name, Script::try_from(name)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Some)
+        },
+    )
+}
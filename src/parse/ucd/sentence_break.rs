@@ -0,0 +1,50 @@
+use crate::codepoint::SentenceBreak;
+use crate::parse::{lookup, property_records, resolve, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// SentenceBreakProperty.txt: "диапазон ; значение # комментарий", по умолчанию - Other
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// Sentence_Break, отсортированные диапазоны
+    pub static ref SENTENCE_BREAK: &'static Vec<(u32, u32, SentenceBreak)> =
+        UnicodeVersion::default().dataset().sb();
+}
+
+/// значение Sentence_Break кодпоинта
+#[cfg(feature = "embedded")]
+pub fn get_sentence_break(code: u32) -> SentenceBreak
+{
+    UnicodeVersion::default().dataset().sentence_break(code)
+}
+
+impl Dataset
+{
+    /// значение Sentence_Break кодпоинта
+    pub fn sentence_break(&self, code: u32) -> SentenceBreak
+    {
+        lookup(self.sb(), code).copied().unwrap_or_default()
+    }
+}
+
+/// разбор SentenceBreakProperty.txt из UCD
+pub(crate) fn sentence_break(source: &str) -> Result<Vec<(u32, u32, SentenceBreak)>, ParseError>
+{
+    let records = property_records(SourceFile::SentenceBreakProperty, source);
+
+    let ranges = resolve(records, |record| {
+        let value = record.field(0)?;
+
+        record
+            .line
+            .property(1, value, SentenceBreak::try_from(value))
+            .map(Some)
+    })?;
+
+    Ok(ranges
+        .into_iter()
+        .filter(|&(_, _, value)| value != SentenceBreak::Other)
+        .collect())
+}
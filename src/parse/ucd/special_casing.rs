@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use crate::parse::{property_records, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// SpecialCasing.txt: "код; lower; title; upper; (условия;)? # комментарий" - отображения,
+// не помещающиеся в UnicodeData.txt: в несколько кодпоинтов, зависящие от языка или контекста.
+// условия - теги языков (tr, az, lt) и контексты (The Unicode Standard, 3.13, таблица 3-17),
+// контекст с префиксом Not_ - отрицание
+
+/// контекст применения отображения регистра
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CasingContext
+{
+    /// перед кодпоинтом - буква с регистром (и игнорируемые), после - нет
+    FinalSigma,
+    /// перед кодпоинтом - Soft_Dotted, без промежуточных CCC 0 или 230
+    AfterSoftDotted,
+    /// после кодпоинта - CCC 230, без промежуточных CCC 0
+    MoreAbove,
+    /// после кодпоинта - U+0307, без промежуточных CCC 0 или 230
+    BeforeDot,
+    /// перед кодпоинтом - U+0049, без промежуточных CCC 0 или 230
+    AfterI,
+}
+
+/// условие применения отображения: контекст, возможно с отрицанием
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CasingCondition
+{
+    pub context: CasingContext,
+    /// Not_ - условие выполняется, если контекста нет
+    pub negated: bool,
+}
+
+/// отображение регистра кодпоинта из SpecialCasing.txt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecialCasing
+{
+    pub code: u32,
+    pub lower: Vec<u32>,
+    pub title: Vec<u32>,
+    pub upper: Vec<u32>,
+    /// язык (tr, az, lt), None - для всех языков
+    pub language: Option<String>,
+    /// контекстные условия, должны выполняться все
+    pub conditions: Vec<CasingCondition>,
+}
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// отображения регистра SpecialCasing.txt, в порядке файла
+    pub static ref SPECIAL_CASING: &'static HashMap<u32, Vec<SpecialCasing>> =
+        UnicodeVersion::default().dataset().special_casing();
+}
+
+impl CasingContext
+{
+    /// название контекста в SpecialCasing.txt
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Self::FinalSigma => "Final_Sigma",
+            Self::AfterSoftDotted => "After_Soft_Dotted",
+            Self::MoreAbove => "More_Above",
+            Self::BeforeDot => "Before_Dot",
+            Self::AfterI => "After_I",
+        }
+    }
+
+    /// контекст по названию в SpecialCasing.txt
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        [
+            Self::FinalSigma,
+            Self::AfterSoftDotted,
+            Self::MoreAbove,
+            Self::BeforeDot,
+            Self::AfterI,
+        ]
+        .into_iter()
+        .find(|context| context.as_str() == name)
+    }
+}
+
+/// разбор SpecialCasing.txt из UCD
+pub(crate) fn special_casing(source: &str) -> Result<HashMap<u32, Vec<SpecialCasing>>, ParseError>
+{
+    let mut map: HashMap<u32, Vec<SpecialCasing>> = HashMap::new();
+
+    for record in property_records(SourceFile::SpecialCasing, source) {
+        let record = record?;
+
+        if record.missing {
+            continue;
+        }
+
+        if record.range.start() != record.range.end() {
+            let range = record.line.text.split(';').next().unwrap_or_default();
+
+            return Err(record
+                .line
+                .error(0, range, ParseErrorKind::Malformed("ожидается код")));
+        }
+
+        let mut language = None;
+        let mut conditions = vec![];
+
+        for condition in record
+            .fields
+            .get(3)
+            .copied()
+            .unwrap_or_default()
+            .split_whitespace()
+        {
+            if condition.bytes().all(|byte| byte.is_ascii_lowercase()) {
+                language = Some(condition.to_owned());
+                continue;
+            }
+
+            let (name, negated) = match condition.strip_prefix("Not_") {
+                Some(name) => (name, true),
+                None => (condition, false),
+            };
+
+            let context = CasingContext::from_name(name).ok_or_else(|| {
+                record.line.error(
+                    4,
+                    condition,
+                    ParseErrorKind::Malformed("неизвестное условие"),
+                )
+            })?;
+
+            conditions.push(CasingCondition { context, negated });
+        }
+
+        let code = *record.range.start();
+
+        map.entry(code).or_default().push(SpecialCasing {
+            code,
+            lower: record.line.codes(1, record.field(0)?)?,
+            title: record.line.codes(2, record.field(1)?)?,
+            upper: record.line.codes(3, record.field(2)?)?,
+            language,
+            conditions,
+        });
+    }
+
+    Ok(map)
+}
@@ -1,27 +1,52 @@
 use std::collections::HashMap;
 
-use crate::{codepoint::*, get_block_by_code};
+use crate::codepoint::*;
+use crate::parse::{Line, ParseError, ParseErrorKind};
+use crate::SourceFile;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
 
+#[cfg(feature = "embedded")]
 lazy_static! {
     /// таблица Unicode
-    pub static ref UNICODE: HashMap<u32, Codepoint> = unicode();
+    pub static ref UNICODE: &'static HashMap<u32, Codepoint> =
+        UnicodeVersion::default().dataset().unicode();
 }
 
-const DATA: &str = include_str!("./../../../data/ucd 15.1.0/UnicodeData.txt");
-
 /// разбор UnicodeData.txt из UCD и составление хешмапа свойств символов Unicode
-fn unicode() -> HashMap<u32, Codepoint>
+pub(crate) fn unicode(source: &str) -> Result<HashMap<u32, Codepoint>, ParseError>
 {
     let mut map: HashMap<u32, Codepoint> = HashMap::new();
 
     // пригодится, когда встретим диапазоны
     let mut range_start: Option<Codepoint> = None;
 
-    for line in DATA.lines() {
-        let props: Vec<&str> = line.split(';').collect();
+    for line in Line::iter(SourceFile::UnicodeData, source) {
+        if line.text.is_empty() {
+            continue;
+        }
+
+        let props: Vec<&str> = line.text.split(';').collect();
+
+        if props.len() != 15 {
+            return Err(line.error(
+                0,
+                line.text,
+                ParseErrorKind::FieldsCount {
+                    expected: 15,
+                    found: props.len(),
+                },
+            ));
+        }
+
+        macro_rules! property {
+            ($type:ty, $field:expr) => {
+                line.property($field, props[$field], <$type>::try_from(props[$field]))?
+            };
+        }
 
         // код и название
-        let code = u32::from_str_radix(props[0], 16).unwrap();
+        let code = line.hex(0, props[0])?;
         let name = props[1].to_owned();
 
         // начинается Private Use
@@ -30,23 +55,27 @@ fn unicode() -> HashMap<u32, Codepoint>
         }
 
         // категория и CCC
-        let gc = GeneralCategory::try_from(props[2]).unwrap();
-        let ccc = CanonicalCombiningClass::try_from(props[3]).unwrap();
+        let gc = property!(GeneralCategory, 2);
+        let ccc = property!(CanonicalCombiningClass, 3);
 
         // Bidi класс и Bidi Mirrored
-        let bc = BidiClass::try_from(props[4]).unwrap();
-        let bidi_mirrored = BidiMirrored::try_from(props[9]).unwrap();
+        let bc = property!(BidiClass, 4);
+        let bidi_mirrored = property!(BidiMirrored, 9);
 
         // декомпозиция и тег декомпозиции
-        let decomposition = Decomposition::try_from(props[5]).unwrap();
+        let decomposition = property!(Decomposition, 5);
 
         // различные numeric значения
-        let numeric = NumericType::try_from((props[6], props[7], props[8])).unwrap();
+        let numeric = line.property(
+            6,
+            props[6 ..= 8].join(";").as_str(),
+            NumericType::try_from((props[6], props[7], props[8])),
+        )?;
 
         // связанные символы в другом регистре (если есть)
-        let simple_uppercase_mapping = SimpleCaseMapping::try_from(props[12]).unwrap();
-        let simple_lowercase_mapping = SimpleCaseMapping::try_from(props[13]).unwrap();
-        let simple_titlecase_mapping = SimpleCaseMapping::try_from(props[14]).unwrap();
+        let simple_uppercase_mapping = property!(SimpleCaseMapping, 12);
+        let simple_lowercase_mapping = property!(SimpleCaseMapping, 13);
+        let simple_titlecase_mapping = property!(SimpleCaseMapping, 14);
 
         // пропускаем колонки 10, 11:
         //
@@ -66,7 +95,6 @@ fn unicode() -> HashMap<u32, Codepoint>
             simple_titlecase_mapping,
             decomposition_tag: decomposition.tag,
             decomposition: decomposition.codes,
-            block: get_block_by_code(code),
         };
 
         // различные блоки
@@ -103,8 +131,17 @@ fn unicode() -> HashMap<u32, Codepoint>
                 continue;
             }
 
-            if name.ends_with("Last>") && range_start.is_some() {
-                let group = range_start.unwrap();
+            if name.ends_with("Last>") {
+                let group = match range_start.take() {
+                    Some(group) => group,
+                    None => {
+                        return Err(line.error(
+                            1,
+                            &name,
+                            ParseErrorKind::Malformed("конец диапазона без начала"),
+                        ))
+                    }
+                };
                 let group_name = &group.name[1 .. group.name.len() - 8];
 
                 // в данном случае, для нас не важны названия символов
@@ -118,8 +155,6 @@ fn unicode() -> HashMap<u32, Codepoint>
 
                     map.insert(i, codepoint);
                 }
-
-                range_start = None;
             }
 
             continue;
@@ -128,5 +163,5 @@ fn unicode() -> HashMap<u32, Codepoint>
         map.insert(codepoint.code, codepoint);
     }
 
-    map
+    Ok(map)
 }
@@ -0,0 +1,50 @@
+use crate::codepoint::WordBreak;
+use crate::parse::{lookup, property_records, resolve, ParseError};
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{Dataset, SourceFile};
+
+// WordBreakProperty.txt: "диапазон ; значение # комментарий", по умолчанию - Other
+
+#[cfg(feature = "embedded")]
+lazy_static! {
+    /// Word_Break, отсортированные диапазоны
+    pub static ref WORD_BREAK: &'static Vec<(u32, u32, WordBreak)> =
+        UnicodeVersion::default().dataset().wb();
+}
+
+/// значение Word_Break кодпоинта
+#[cfg(feature = "embedded")]
+pub fn get_word_break(code: u32) -> WordBreak
+{
+    UnicodeVersion::default().dataset().word_break(code)
+}
+
+impl Dataset
+{
+    /// значение Word_Break кодпоинта
+    pub fn word_break(&self, code: u32) -> WordBreak
+    {
+        lookup(self.wb(), code).copied().unwrap_or_default()
+    }
+}
+
+/// разбор WordBreakProperty.txt из UCD
+pub(crate) fn word_break(source: &str) -> Result<Vec<(u32, u32, WordBreak)>, ParseError>
+{
+    let records = property_records(SourceFile::WordBreakProperty, source);
+
+    let ranges = resolve(records, |record| {
+        let value = record.field(0)?;
+
+        record
+            .line
+            .property(1, value, WordBreak::try_from(value))
+            .map(Some)
+    })?;
+
+    Ok(ranges
+        .into_iter()
+        .filter(|&(_, _, value)| value != WordBreak::Other)
+        .collect())
+}
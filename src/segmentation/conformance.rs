@@ -0,0 +1,204 @@
+use crate::parse::breaks;
+use crate::segmentation::boundaries;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+use crate::{BreakTest, Dataset, ParseError, SourceFile};
+
+// проверка соответствия тестам сегментации UCD: границы, найденные итератором, должны совпадать
+// с отмеченными "÷" в строке теста
+
+/// строка теста сегментации, не прошедшая проверку
+#[derive(Clone)]
+pub struct BreakTestFailure
+{
+    /// тест
+    pub test: BreakTest,
+    /// полученные границы
+    pub actual: Vec<usize>,
+}
+
+/// результат проверки тестов сегментации
+#[derive(Debug, Clone)]
+pub struct BreakTestReport
+{
+    /// файл тестов
+    pub file: SourceFile,
+    /// количество успешно пройденных строк
+    pub passed: usize,
+    /// ошибки
+    pub failures: Vec<BreakTestFailure>,
+}
+
+/// проверить тесты сегментации графем набора данных по умолчанию
+#[cfg(feature = "embedded")]
+pub fn grapheme_break_conformance() -> BreakTestReport
+{
+    UnicodeVersion::default()
+        .dataset()
+        .grapheme_break_conformance()
+}
+
+/// проверить тесты границ слов набора данных по умолчанию
+#[cfg(feature = "embedded")]
+pub fn word_break_conformance() -> BreakTestReport
+{
+    UnicodeVersion::default().dataset().word_break_conformance()
+}
+
+/// проверить тесты границ предложений набора данных по умолчанию
+#[cfg(feature = "embedded")]
+pub fn sentence_break_conformance() -> BreakTestReport
+{
+    UnicodeVersion::default()
+        .dataset()
+        .sentence_break_conformance()
+}
+
+/// проверить тесты разрыва строк набора данных по умолчанию
+#[cfg(feature = "embedded")]
+pub fn line_break_conformance() -> BreakTestReport
+{
+    UnicodeVersion::default().dataset().line_break_conformance()
+}
+
+impl Dataset
+{
+    /// проверить тесты сегментации графем (GraphemeBreakTest.txt)
+    pub fn grapheme_break_conformance(&self) -> BreakTestReport
+    {
+        self.try_grapheme_break_conformance()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// проверить тесты сегментации графем; ошибка - если файла тестов нет или он не разбирается
+    pub fn try_grapheme_break_conformance(&self) -> Result<BreakTestReport, ParseError>
+    {
+        Ok(check_breaks(
+            SourceFile::GraphemeBreakTest,
+            self.try_grapheme_break_tests()?,
+            |text| boundaries(self.graphemes(text)),
+        ))
+    }
+
+    /// проверить тесты границ слов (WordBreakTest.txt)
+    pub fn word_break_conformance(&self) -> BreakTestReport
+    {
+        self.try_word_break_conformance()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// проверить тесты границ слов; ошибка - если файла тестов нет или он не разбирается
+    pub fn try_word_break_conformance(&self) -> Result<BreakTestReport, ParseError>
+    {
+        Ok(check_breaks(
+            SourceFile::WordBreakTest,
+            self.try_word_break_tests()?,
+            |text| boundaries(self.words(text)),
+        ))
+    }
+
+    /// проверить тесты границ предложений (SentenceBreakTest.txt)
+    pub fn sentence_break_conformance(&self) -> BreakTestReport
+    {
+        self.try_sentence_break_conformance()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// проверить тесты границ предложений; ошибка - если файла тестов нет или он не разбирается
+    pub fn try_sentence_break_conformance(&self) -> Result<BreakTestReport, ParseError>
+    {
+        Ok(check_breaks(
+            SourceFile::SentenceBreakTest,
+            self.try_sentence_break_tests()?,
+            |text| boundaries(self.sentences(text)),
+        ))
+    }
+
+    /// проверить тесты разрыва строк (LineBreakTest.txt); в начале строки разрыва нет (LB2),
+    /// поэтому смещение 0 в границы не входит
+    pub fn line_break_conformance(&self) -> BreakTestReport
+    {
+        self.try_line_break_conformance()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// проверить тесты разрыва строк; ошибка - если файла тестов нет или он не разбирается
+    pub fn try_line_break_conformance(&self) -> Result<BreakTestReport, ParseError>
+    {
+        Ok(check_breaks(
+            SourceFile::LineBreakTest,
+            self.try_line_break_tests()?,
+            |text| boundaries(self.line_breaks(text).map(|(line, _)| line))[1 ..].to_vec(),
+        ))
+    }
+}
+
+/// сравнить границы, найденные сегментацией, с ожидаемыми
+fn check_breaks(
+    file: SourceFile,
+    tests: &[BreakTest],
+    segment: impl Fn(&str) -> Vec<usize>,
+) -> BreakTestReport
+{
+    let mut report = BreakTestReport {
+        file,
+        passed: 0,
+        failures: vec![],
+    };
+
+    for test in tests {
+        let actual = segment(&test.text);
+
+        match actual == test.boundaries {
+            true => report.passed += 1,
+            false => report.failures.push(BreakTestFailure {
+                test: test.clone(),
+                actual,
+            }),
+        }
+    }
+
+    report
+}
+
+impl BreakTestReport
+{
+    /// все строки прошли проверку
+    pub fn is_ok(&self) -> bool
+    {
+        self.failures.is_empty()
+    }
+}
+
+impl core::fmt::Debug for BreakTestFailure
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        writeln!(
+            f,
+            "{:?}получено: {}",
+            self.test,
+            breaks(&self.test.text, &self.actual)
+        )
+    }
+}
+
+impl core::fmt::Display for BreakTestReport
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(
+            f,
+            "{}: пройдено {}, ошибок {}",
+            self.file.file_names()[0],
+            self.passed,
+            self.failures.len()
+        )?;
+
+        for failure in &self.failures {
+            write!(f, "{:?}", failure)?;
+        }
+
+        Ok(())
+    }
+}
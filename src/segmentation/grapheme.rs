@@ -0,0 +1,166 @@
+use crate::codepoint::{BinaryProperty, GraphemeClusterBreak, IndicConjunctBreak};
+use crate::Dataset;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// границы расширенных кластеров графем (UAX #29, 3.1.1):
+//  GB3     CR × LF
+//  GB4, 5  границы до и после Control | CR | LF
+//  GB6 - 8 слоги хангыль: L × (L | V | LV | LVT), (LV | V) × (V | T), (LVT | T) × T
+//  GB9     × (Extend | ZWJ),  GB9a  × SpacingMark,  GB9b  Prepend ×
+//  GB9c    InCB=Consonant [InCB=Extend InCB=Linker]* InCB=Linker [...]* × InCB=Consonant
+//  GB11    ExtPict Extend* ZWJ × ExtPict
+//  GB12, 13 пары региональных индикаторов
+//  GB999   в остальных случаях - граница
+
+use GraphemeClusterBreak::*;
+
+/// итератор по расширенным кластерам графем строки
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a>
+{
+    dataset: &'a Dataset,
+    text: &'a str,
+    position: usize,
+}
+
+/// контекст текущего кластера графем
+struct GraphemeState<'a>
+{
+    dataset: &'a Dataset,
+    /// значение Grapheme_Cluster_Break предыдущего кодпоинта
+    previous: GraphemeClusterBreak,
+    /// количество региональных индикаторов подряд перед границей
+    regional: usize,
+    /// ExtPict Extend* перед границей
+    pictographic: bool,
+    /// ExtPict Extend* ZWJ перед границей
+    pictographic_zwj: bool,
+    /// конъюнкт: None - нет, Some(false) - согласная, Some(true) - согласная и вирама (Linker)
+    conjunct: Option<bool>,
+}
+
+/// расширенные кластеры графем строки
+#[cfg(feature = "embedded")]
+pub fn graphemes(text: &str) -> Graphemes<'_>
+{
+    UnicodeVersion::default().dataset().graphemes(text)
+}
+
+impl Dataset
+{
+    /// расширенные кластеры графем строки
+    pub fn graphemes<'a>(&'a self, text: &'a str) -> Graphemes<'a>
+    {
+        Graphemes {
+            dataset: self,
+            text,
+            position: 0,
+        }
+    }
+}
+
+impl<'a> Graphemes<'a>
+{
+    /// смещение в байтах начала следующего кластера
+    pub fn offset(&self) -> usize
+    {
+        self.position
+    }
+}
+
+impl<'a> Iterator for Graphemes<'a>
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let rest = &self.text[self.position ..];
+        let mut chars = rest.char_indices();
+
+        let (_, first) = chars.next()?;
+        let mut state = GraphemeState::new(self.dataset, u32::from(first));
+
+        let end = chars
+            .find(|&(_, c)| state.is_boundary(u32::from(c)))
+            .map_or(rest.len(), |(offset, _)| offset);
+
+        self.position += end;
+
+        Some(&rest[.. end])
+    }
+}
+
+impl<'a> GraphemeState<'a>
+{
+    /// контекст кластера, начинающегося с кодпоинта
+    fn new(dataset: &'a Dataset, code: u32) -> Self
+    {
+        let mut state = Self {
+            dataset,
+            previous: Other,
+            regional: 0,
+            pictographic: false,
+            pictographic_zwj: false,
+            conjunct: None,
+        };
+
+        state.push(code, dataset.grapheme_cluster_break(code));
+        state
+    }
+
+    /// есть ли граница перед кодпоинтом; если нет - кодпоинт добавляется в контекст
+    fn is_boundary(&mut self, code: u32) -> bool
+    {
+        let value = self.dataset.grapheme_cluster_break(code);
+
+        let boundary = match (self.previous, value) {
+            (Cr, Lf) => false,
+            (Control | Cr | Lf, _) | (_, Control | Cr | Lf) => true,
+            (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,
+            (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,
+            _ if self.conjunct == Some(true)
+                && self.dataset.indic_conjunct_break(code) == IndicConjunctBreak::Consonant =>
+            {
+                false
+            }
+            (Zwj, _) if self.pictographic_zwj && self.is_pictographic(code) => false,
+            (RegionalIndicator, RegionalIndicator) => self.regional.is_multiple_of(2),
+            _ => true,
+        };
+
+        if !boundary {
+            self.push(code, value);
+        }
+
+        boundary
+    }
+
+    /// добавить кодпоинт в контекст
+    fn push(&mut self, code: u32, value: GraphemeClusterBreak)
+    {
+        self.regional = match value {
+            RegionalIndicator => self.regional + 1,
+            _ => 0,
+        };
+
+        self.pictographic_zwj = value == Zwj && self.pictographic;
+        self.pictographic = self.is_pictographic(code) || (value == Extend && self.pictographic);
+
+        self.conjunct = match self.dataset.indic_conjunct_break(code) {
+            IndicConjunctBreak::Consonant => Some(false),
+            IndicConjunctBreak::Linker => self.conjunct.map(|_| true),
+            IndicConjunctBreak::Extend => self.conjunct,
+            IndicConjunctBreak::None => None,
+        };
+
+        self.previous = value;
+    }
+
+    /// Extended_Pictographic (emoji-data.txt)
+    fn is_pictographic(&self, code: u32) -> bool
+    {
+        self.dataset
+            .has_property(code, BinaryProperty::ExtendedPictographic)
+    }
+}
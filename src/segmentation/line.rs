@@ -0,0 +1,438 @@
+use crate::codepoint::{BinaryProperty, GeneralCategory, LineBreak};
+use crate::{Dataset, UnicodeVersion};
+
+// разрыв строк (UAX #14, 6.1 - 6.2): классы разрешаются по LB1, затем правила применяются по
+// порядку до первого подходящего:
+//  LB4 - 6     BK !,  CR × LF,  (CR | LF | NL) !,  × (BK | CR | LF | NL)
+//  LB7 - 8a    × SP,  × ZW,  ZW SP* ÷,  ZWJ ×
+//  LB9, 10     X (CM | ZWJ)* -> X, кроме X = BK, CR, LF, NL, SP, ZW; оставшиеся CM, ZWJ -> AL
+//  LB11 - 13   × WJ, WJ ×,  GL ×,  [^SP BA HY HH] × GL,  × (CL | CP | EX | SY)
+//  LB14 - 17   OP SP* ×,  кавычки Pi / Pf,  SP ÷ IS NU,  × IS,  (CL | CP) SP* × NS,  B2 SP* × B2
+//  LB18        SP ÷
+//  LB19, 19a   кавычки QU - без разрыва, кроме восточноазиатского контекста
+//  LB20 - 22   ÷ CB ÷,  дефисы в начале слова,  × (BA | HH | HY | NS),  BB ×,  SY × HL,  × IN
+//  LB23 - 25   буквы, цифры, префиксы и постфиксы чисел
+//  LB26, 27    слоги хангыль
+//  LB28 - 29   (AL | HL) × (AL | HL),  акшары,  IS × (AL | HL)
+//  LB30 - 30b  скобки вне восточноазиатского контекста, пары RI, модификаторы эмодзи
+//  LB31        в остальных случаях - разрыв
+//
+// набор данных 15.1.0 разбивается по правилам UAX #14 этой версии: нет LB15c, LB15d (× IS), LB19a
+// (× QU, QU ×) и LB20a, LB21a - HL (HY | BA) ×
+
+use BreakOpportunity::*;
+use LineBreak::*;
+
+/// U+25CC DOTTED CIRCLE - в правиле LB28a используется наравне с акшарами
+const DOTTED_CIRCLE: u32 = 0x25CC;
+
+/// редакция правил разрыва строк
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rules
+{
+    /// UAX #14 для Unicode 15.1.0
+    V15_1,
+    /// UAX #14 для Unicode 16.0.0 и новее
+    Current,
+}
+
+/// вид возможности разрыва строки
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakOpportunity
+{
+    /// обязательный разрыв: после BK, CR, LF, NL и в конце строки
+    Mandatory,
+    /// допустимый разрыв
+    Allowed,
+}
+
+/// итератор по отрезкам строки между возможностями разрыва, с видом разрыва в конце отрезка
+#[derive(Debug, Clone)]
+pub struct LineBreaks<'a>
+{
+    text: &'a str,
+    breaks: Vec<(usize, BreakOpportunity)>,
+    index: usize,
+}
+
+/// кодпоинт строки и свойства, используемые правилами разрыва
+struct LineChar
+{
+    /// смещение в байтах
+    offset: usize,
+    /// код
+    code: u32,
+    /// класс после разрешения по LB1
+    class: LineBreak,
+    /// индекс первого кодпоинта сочетания X (CM | ZWJ)* (LB9), к которому относится кодпоинт
+    base: usize,
+    /// общая категория
+    gc: GeneralCategory,
+    /// East_Asian_Width - F, W или H
+    east_asian: bool,
+    /// Extended_Pictographic
+    pictographic: bool,
+}
+
+/// отрезки строки между возможностями разрыва
+#[cfg(feature = "embedded")]
+pub fn line_breaks(text: &str) -> LineBreaks<'_>
+{
+    UnicodeVersion::default().dataset().line_breaks(text)
+}
+
+impl Dataset
+{
+    /// отрезки строки между возможностями разрыва
+    pub fn line_breaks<'a>(&self, text: &'a str) -> LineBreaks<'a>
+    {
+        LineBreaks {
+            text,
+            breaks: break_opportunities(self, text),
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for LineBreaks<'a>
+{
+    type Item = (&'a str, BreakOpportunity);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let &(end, opportunity) = self.breaks.get(self.index)?;
+
+        let start = match self.index {
+            0 => 0,
+            index => self.breaks[index - 1].0,
+        };
+
+        self.index += 1;
+
+        Some((&self.text[start .. end], opportunity))
+    }
+}
+
+/// возможности разрыва строки: смещения в байтах, не включая 0, и вид разрыва
+fn break_opportunities(dataset: &Dataset, text: &str) -> Vec<(usize, BreakOpportunity)>
+{
+    let mut chars: Vec<LineChar> = vec![];
+
+    for (offset, c) in text.char_indices() {
+        let code = u32::from(c);
+
+        let gc = dataset
+            .unicode()
+            .get(&code)
+            .map_or(GeneralCategory::Unassigned, |codepoint| codepoint.gc);
+
+        let class = dataset.line_break(code).resolve(gc);
+
+        // LB9: CM и ZWJ относятся к предыдущему кодпоинту
+        let base = match chars.last() {
+            Some(previous)
+                if matches!(class, CombiningMark | ZeroWidthJoiner)
+                    && !previous.class.is_mandatory_break()
+                    && !matches!(previous.class, Space | ZeroWidthSpace) =>
+            {
+                previous.base
+            }
+            _ => chars.len(),
+        };
+
+        chars.push(LineChar {
+            offset,
+            code,
+            class,
+            base,
+            gc,
+            east_asian: dataset.east_asian_width(code).is_east_asian(),
+            pictographic: dataset.has_property(code, BinaryProperty::ExtendedPictographic),
+        });
+    }
+
+    let rules = match dataset.version() {
+        Some(UnicodeVersion::V15_1_0) => Rules::V15_1,
+        _ => Rules::Current,
+    };
+
+    let mut result = vec![];
+
+    for i in 1 .. chars.len() {
+        if let Some(opportunity) = line_break_before(&chars, i, rules) {
+            result.push((chars[i].offset, opportunity));
+        }
+    }
+
+    if !text.is_empty() {
+        result.push((text.len(), Mandatory));
+    }
+
+    result
+}
+
+/// возможность разрыва строки перед кодпоинтом i
+fn line_break_before(chars: &[LineChar], i: usize, rules: Rules) -> Option<BreakOpportunity>
+{
+    match (chars[i - 1].class, chars[i].class) {
+        (MandatoryBreak, _) => return Some(Mandatory),
+        (CarriageReturn, LineFeed) => return None,
+        (CarriageReturn | LineFeed | NextLine, _) => return Some(Mandatory),
+        (_, MandatoryBreak | CarriageReturn | LineFeed | NextLine) => return None,
+        (_, Space | ZeroWidthSpace) => return None,
+        _ => (),
+    }
+
+    // сочетание X (CM | ZWJ)* (LB9), к которому относится кодпоинт; оставшиеся CM и ZWJ - AL
+    let unit = |j: usize| &chars[chars[j].base];
+    let class = |j: usize| match unit(j).class {
+        CombiningMark | ZeroWidthJoiner => Alphabetic,
+        value => value,
+    };
+
+    // последний кодпоинт предыдущего сочетания, первый кодпоинт следующего
+    let before = |j: usize| chars[j].base.checked_sub(1);
+    let after = |j: usize| (j + 1 .. chars.len()).find(|&k| chars[k].base == k);
+
+    // пропустить сочетания перед j (включительно) с классами из списка
+    let skip = |mut j: Option<usize>, classes: &[LineBreak]| {
+        while let Some(k) = j.filter(|&k| classes.contains(&class(k))) {
+            j = before(k);
+        }
+        j
+    };
+
+    let spaced = skip(Some(i - 1), &[Space]);
+
+    if spaced.map(class) == Some(ZeroWidthSpace) {
+        return Some(Allowed);
+    }
+
+    if chars[i - 1].class == ZeroWidthJoiner || chars[i].base != i {
+        return None;
+    }
+
+    let left = class(i - 1);
+    let right = class(i);
+    let previous = before(i - 1);
+    let next = after(i);
+
+    let east_asian = |j: usize| unit(j).east_asian;
+    let dotted = |j: usize| unit(j).code == DOTTED_CIRCLE;
+    let aksara = |j: usize| matches!(class(j), Aksara | AksaraStart) || dotted(j);
+
+    match (left, right) {
+        (WordJoiner, _) | (_, WordJoiner) | (Glue, _) => return None,
+        (Space | BreakAfter | Hyphen | UnambiguousHyphen, Glue) => (),
+        (_, Glue) => return None,
+        (_, ClosePunctuation | CloseParenthesis | Exclamation | BreakSymbols) => return None,
+        _ => (),
+    }
+
+    // LB14 - 17: OP SP* ×, кавычки, IS, NS и B2 после пробелов
+    if let Some(j) = spaced {
+        if class(j) == OpenPunctuation {
+            return None;
+        }
+
+        let initial = class(j) == Quotation
+            && unit(j).gc == GeneralCategory::InitialPunctuation
+            && before(j).is_none_or(|k| {
+                class(k).is_mandatory_break()
+                    || matches!(
+                        class(k),
+                        OpenPunctuation | Quotation | Glue | Space | ZeroWidthSpace
+                    )
+            });
+
+        if initial {
+            return None;
+        }
+    }
+
+    let closing = right == Quotation
+        && unit(i).gc == GeneralCategory::FinalPunctuation
+        && next.is_none_or(|k| {
+            class(k).is_mandatory_break()
+                || matches!(
+                    class(k),
+                    Space
+                        | Glue
+                        | WordJoiner
+                        | ClosePunctuation
+                        | Quotation
+                        | CloseParenthesis
+                        | Exclamation
+                        | InfixNumeric
+                        | BreakSymbols
+                        | ZeroWidthSpace
+                )
+        });
+
+    if closing {
+        return None;
+    }
+
+    if right == InfixNumeric {
+        let number = left == Space && next.map(class) == Some(Numeric);
+
+        return match rules == Rules::Current && number {
+            true => Some(Allowed),
+            false => None,
+        };
+    }
+
+    match (spaced.map(class), right) {
+        (Some(ClosePunctuation | CloseParenthesis), Nonstarter) => return None,
+        (Some(BreakBoth), BreakBoth) => return None,
+        _ => (),
+    }
+
+    if left == Space {
+        return Some(Allowed);
+    }
+
+    // LB19, 19a: кавычки
+    if rules == Rules::V15_1 && (left == Quotation || right == Quotation) {
+        return None;
+    }
+
+    if right == Quotation
+        && (unit(i).gc != GeneralCategory::InitialPunctuation
+            || !east_asian(i - 1)
+            || next.is_none_or(|k| !east_asian(k)))
+    {
+        return None;
+    }
+
+    if left == Quotation
+        && (unit(i - 1).gc != GeneralCategory::FinalPunctuation
+            || !east_asian(i)
+            || previous.is_none_or(|k| !east_asian(k)))
+    {
+        return None;
+    }
+
+    if left == ContingentBreak || right == ContingentBreak {
+        return Some(Allowed);
+    }
+
+    // LB20a - 22
+    match (left, right) {
+        (Hyphen | UnambiguousHyphen, Alphabetic | HebrewLetter)
+            if rules == Rules::Current
+                && previous.is_none_or(|k| {
+                    class(k).is_mandatory_break()
+                        || matches!(class(k), Space | ZeroWidthSpace | ContingentBreak | Glue)
+                }) =>
+        {
+            return None
+        }
+        (_, BreakAfter | UnambiguousHyphen | Hyphen | Nonstarter) | (BreakBefore, _) => {
+            return None
+        }
+        (Hyphen | BreakAfter, _)
+            if rules == Rules::V15_1 && previous.map(class) == Some(HebrewLetter) =>
+        {
+            return None
+        }
+        (Hyphen | UnambiguousHyphen, _)
+            if rules == Rules::Current
+                && right != HebrewLetter
+                && previous.map(class) == Some(HebrewLetter) =>
+        {
+            return None
+        }
+        (BreakSymbols, HebrewLetter) | (_, Inseparable) => return None,
+        _ => (),
+    }
+
+    // LB23 - 24: буквы, иероглифы и числа с префиксами и постфиксами
+    match (left, right) {
+        (Alphabetic | HebrewLetter, Numeric) | (Numeric, Alphabetic | HebrewLetter) => return None,
+        (PrefixNumeric, Ideographic | EmojiBase | EmojiModifier)
+        | (Ideographic | EmojiBase | EmojiModifier, PostfixNumeric) => return None,
+        (PrefixNumeric | PostfixNumeric, Alphabetic | HebrewLetter)
+        | (Alphabetic | HebrewLetter, PrefixNumeric | PostfixNumeric) => return None,
+        _ => (),
+    }
+
+    // LB25: NU (SY | IS)* (CL | CP)? × (PO | PR), (PO | PR) × OP IS? NU,
+    // (PO | PR | HY | IS) × NU, NU (SY | IS)* × NU
+    let number =
+        |j: Option<usize>| skip(j, &[BreakSymbols, InfixNumeric]).map(class) == Some(Numeric);
+
+    match (left, right) {
+        (ClosePunctuation | CloseParenthesis, PostfixNumeric | PrefixNumeric)
+            if number(previous) =>
+        {
+            return None
+        }
+        (_, PostfixNumeric | PrefixNumeric) if number(Some(i - 1)) => return None,
+        (PostfixNumeric | PrefixNumeric, OpenPunctuation) => {
+            let numeric = match next.map(class) {
+                Some(Numeric) => true,
+                Some(InfixNumeric) => next.and_then(after).map(class) == Some(Numeric),
+                _ => false,
+            };
+
+            if numeric {
+                return None;
+            }
+        }
+        (PostfixNumeric | PrefixNumeric | Hyphen | InfixNumeric, Numeric) => return None,
+        (_, Numeric) if number(Some(i - 1)) => return None,
+        _ => (),
+    }
+
+    // LB26 - 29: хангыль, буквы, акшары
+    match (left, right) {
+        (HangulLJamo, HangulLJamo | HangulVJamo | HangulLvSyllable | HangulLvtSyllable)
+        | (HangulVJamo | HangulLvSyllable, HangulVJamo | HangulTJamo)
+        | (HangulTJamo | HangulLvtSyllable, HangulTJamo) => return None,
+        (
+            HangulLJamo | HangulVJamo | HangulTJamo | HangulLvSyllable | HangulLvtSyllable,
+            PostfixNumeric,
+        )
+        | (
+            PrefixNumeric,
+            HangulLJamo | HangulVJamo | HangulTJamo | HangulLvSyllable | HangulLvtSyllable,
+        ) => return None,
+        (Alphabetic | HebrewLetter, Alphabetic | HebrewLetter) => return None,
+        (AksaraPrebase, _) if aksara(i) => return None,
+        (_, ViramaFinal | Virama) if aksara(i - 1) => return None,
+        (Virama, _) if previous.is_some_and(aksara) && (right == Aksara || dotted(i)) => {
+            return None
+        }
+        _ if aksara(i - 1) && aksara(i) && next.map(class) == Some(ViramaFinal) => return None,
+        (InfixNumeric, Alphabetic | HebrewLetter) => return None,
+        _ => (),
+    }
+
+    // LB30 - 30b
+    match (left, right) {
+        (Alphabetic | HebrewLetter | Numeric, OpenPunctuation) if !east_asian(i) => None,
+        (CloseParenthesis, Alphabetic | HebrewLetter | Numeric) if !east_asian(i - 1) => None,
+        (RegionalIndicator, RegionalIndicator) => {
+            let mut count = 0;
+            let mut j = Some(i - 1);
+
+            while let Some(k) = j.filter(|&k| class(k) == RegionalIndicator) {
+                count += 1;
+                j = before(k);
+            }
+
+            match count % 2 {
+                1 => None,
+                _ => Some(Allowed),
+            }
+        }
+        (EmojiBase, EmojiModifier) => None,
+        (_, EmojiModifier)
+            if unit(i - 1).pictographic && unit(i - 1).gc == GeneralCategory::Unassigned =>
+        {
+            None
+        }
+        _ => Some(Allowed),
+    }
+}
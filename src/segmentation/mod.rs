@@ -0,0 +1,29 @@
+mod conformance;
+mod grapheme;
+mod line;
+mod sentence;
+mod word;
+
+pub use conformance::*;
+pub use grapheme::*;
+pub use line::*;
+pub use sentence::*;
+pub use word::*;
+
+// сегментация текста, UAX #29 (Unicode Text Segmentation) и UAX #14 (Line Breaking): границы
+// определяются правилами по значениям свойства *_Break соседних кодпоинтов и, для некоторых
+// правил, - по контексту: графемы - по состоянию текущего кластера, слова, предложения и разрывы
+// строк - по кодпоинтам до и после границы, без учёта расширяющих символов (границы строки
+// вычисляются сразу)
+
+/// смещения границ сегментов в байтах, включая 0 и длину строки
+pub(crate) fn boundaries<'a>(segments: impl Iterator<Item = &'a str>) -> Vec<usize>
+{
+    let mut result = vec![0];
+
+    for segment in segments {
+        result.push(result[result.len() - 1] + segment.len());
+    }
+
+    result
+}
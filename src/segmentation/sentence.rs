@@ -0,0 +1,164 @@
+use crate::codepoint::SentenceBreak;
+use crate::Dataset;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// границы предложений (UAX #29, 5.1.1):
+//  SB3     CR × LF
+//  SB4     ParaSep ÷
+//  SB5     X (Extend | Format)* -> X: остальные правила не видят расширяющих символов
+//  SB6, 7  ATerm × Numeric,  (Upper | Lower) ATerm × Upper - числа и сокращения
+//  SB8     ATerm Close* Sp* × (¬(OLetter | Upper | Lower | ParaSep | SATerm))* Lower
+//  SB8a    SATerm Close* Sp* × (SContinue | SATerm)
+//  SB9, 10 SATerm Close* × (Close | Sp | ParaSep),  SATerm Close* Sp* × (Sp | ParaSep)
+//  SB11    SATerm Close* Sp* ParaSep? ÷
+//  SB998   в остальных случаях - нет границы
+
+use SentenceBreak::*;
+
+/// итератор по предложениям строки
+#[derive(Debug, Clone)]
+pub struct Sentences<'a>
+{
+    text: &'a str,
+    boundaries: Vec<usize>,
+    index: usize,
+}
+
+/// предложения строки
+#[cfg(feature = "embedded")]
+pub fn sentences(text: &str) -> Sentences<'_>
+{
+    UnicodeVersion::default().dataset().sentences(text)
+}
+
+impl Dataset
+{
+    /// предложения строки
+    pub fn sentences<'a>(&self, text: &'a str) -> Sentences<'a>
+    {
+        Sentences {
+            text,
+            boundaries: sentence_boundaries(self, text),
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Sentences<'a>
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        match self.boundaries.get(self.index .. self.index + 2) {
+            Some(&[start, end]) => {
+                self.index += 1;
+                Some(&self.text[start .. end])
+            }
+            _ => None,
+        }
+    }
+}
+
+/// смещения границ предложений в байтах, включая 0 и длину строки
+fn sentence_boundaries(dataset: &Dataset, text: &str) -> Vec<usize>
+{
+    let chars: Vec<(usize, SentenceBreak)> = text
+        .char_indices()
+        .map(|(offset, c)| (offset, dataset.sentence_break(u32::from(c))))
+        .collect();
+
+    let mut result = vec![0];
+
+    for i in 1 .. chars.len() {
+        if is_sentence_boundary(&chars, i) {
+            result.push(chars[i].0);
+        }
+    }
+
+    if !text.is_empty() {
+        result.push(text.len());
+    }
+
+    result
+}
+
+/// игнорируемые правилами SB6 - SB11 кодпоинты (SB5)
+fn is_ignored(value: SentenceBreak) -> bool
+{
+    matches!(value, Extend | Format)
+}
+
+/// есть ли граница предложений перед кодпоинтом i
+fn is_sentence_boundary(chars: &[(usize, SentenceBreak)], i: usize) -> bool
+{
+    let class = |j: usize| chars[j].1;
+    let previous = |j: usize| (0 .. j).rev().find(|&k| !is_ignored(class(k)));
+
+    match (class(i - 1), class(i)) {
+        (Cr, Lf) => return false,
+        (previous, _) if previous.is_paragraph_separator() => return true,
+        (_, Extend | Format) => return false,
+        _ => (),
+    }
+
+    // SB5: расширяющие символы относятся к предыдущему кодпоинту, кроме начала строки и ParaSep
+    let left = match previous(i) {
+        Some(j) if !class(j).is_paragraph_separator() => j,
+        _ => i - 1,
+    };
+
+    let current = class(i);
+
+    match class(left) {
+        ATerm if current == Numeric => return false,
+        ATerm
+            if current == Upper
+                && previous(left).is_some_and(|j| matches!(class(j), Upper | Lower)) =>
+        {
+            return false
+        }
+        _ => (),
+    }
+
+    // SATerm Close* Sp*
+    let mut j = Some(left);
+    let mut spaces = false;
+
+    while let Some(k) = j.filter(|&k| class(k) == Sp) {
+        spaces = true;
+        j = previous(k);
+    }
+
+    while let Some(k) = j.filter(|&k| class(k) == Close) {
+        j = previous(k);
+    }
+
+    let terminator = match j.map(class).filter(|value| value.is_terminator()) {
+        Some(terminator) => terminator,
+        None => return false,
+    };
+
+    if terminator == ATerm {
+        let following = (i .. chars.len())
+            .map(class)
+            .filter(|&value| !is_ignored(value))
+            .find(|value| {
+                matches!(value, OLetter | Upper | Lower)
+                    || value.is_paragraph_separator()
+                    || value.is_terminator()
+            });
+
+        if following == Some(Lower) {
+            return false;
+        }
+    }
+
+    match current {
+        SContinue | STerm | ATerm => false,
+        Close if !spaces => false,
+        Sp | Sep | Cr | Lf => false,
+        _ => true,
+    }
+}
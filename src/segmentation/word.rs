@@ -0,0 +1,188 @@
+use crate::codepoint::{BinaryProperty, WordBreak};
+use crate::Dataset;
+#[cfg(feature = "embedded")]
+use crate::UnicodeVersion;
+
+// границы слов (UAX #29, 4.1.1):
+//  WB3     CR × LF
+//  WB3a, b границы до и после Newline | CR | LF
+//  WB3c    ZWJ × ExtPict,  WB3d  WSegSpace × WSegSpace
+//  WB4     X (Extend | Format | ZWJ)* -> X: остальные правила не видят расширяющих символов
+//  WB5 - 7c  буквы, в т.ч. через MidLetter / MidNumLet / Single_Quote и кавычки иврита
+//  WB8 - 12  числа и буквы с числами, в т.ч. через MidNum / MidNumLet / Single_Quote
+//  WB13 - 13b  катакана и соединители ExtendNumLet
+//  WB15, 16  пары региональных индикаторов
+//  WB999   в остальных случаях - граница
+
+use WordBreak::*;
+
+/// итератор по сегментам строки между границами слов
+#[derive(Debug, Clone)]
+pub struct Words<'a>
+{
+    dataset: &'a Dataset,
+    text: &'a str,
+    boundaries: Vec<usize>,
+    index: usize,
+    skip_non_words: bool,
+}
+
+/// сегменты строки между границами слов
+#[cfg(feature = "embedded")]
+pub fn words(text: &str) -> Words<'_>
+{
+    UnicodeVersion::default().dataset().words(text)
+}
+
+impl Dataset
+{
+    /// сегменты строки между границами слов
+    pub fn words<'a>(&'a self, text: &'a str) -> Words<'a>
+    {
+        Words {
+            dataset: self,
+            text,
+            boundaries: word_boundaries(self, text),
+            index: 0,
+            skip_non_words: false,
+        }
+    }
+}
+
+impl<'a> Words<'a>
+{
+    /// пропускать сегменты без букв и цифр (пробелы, знаки препинания, символы)
+    pub fn skip_non_words(mut self) -> Self
+    {
+        self.skip_non_words = true;
+        self
+    }
+
+    /// сегмент содержит букву, цифру или соединитель
+    fn is_word(&self, segment: &str) -> bool
+    {
+        segment.chars().map(u32::from).any(|code| {
+            let word = matches!(
+                self.dataset.word_break(code),
+                ALetter | HebrewLetter | Numeric | Katakana | ExtendNumLet
+            );
+
+            word || self
+                .dataset
+                .unicode()
+                .get(&code)
+                .is_some_and(|codepoint| codepoint.gc.is_letter() || codepoint.gc.is_numeric())
+        })
+    }
+}
+
+impl<'a> Iterator for Words<'a>
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        while let Some(&[start, end]) = self.boundaries.get(self.index .. self.index + 2) {
+            self.index += 1;
+
+            let segment = &self.text[start .. end];
+
+            if !self.skip_non_words || self.is_word(segment) {
+                return Some(segment);
+            }
+        }
+
+        None
+    }
+}
+
+/// смещения границ слов в байтах, включая 0 и длину строки
+fn word_boundaries(dataset: &Dataset, text: &str) -> Vec<usize>
+{
+    let chars: Vec<(usize, u32, WordBreak)> = text
+        .char_indices()
+        .map(|(offset, c)| (offset, u32::from(c), dataset.word_break(u32::from(c))))
+        .collect();
+
+    let mut result = vec![0];
+
+    for i in 1 .. chars.len() {
+        if is_word_boundary(dataset, &chars, i) {
+            result.push(chars[i].0);
+        }
+    }
+
+    if !text.is_empty() {
+        result.push(text.len());
+    }
+
+    result
+}
+
+/// игнорируемые правилами WB5 - WB16 кодпоинты (WB4)
+fn is_ignored(value: WordBreak) -> bool
+{
+    matches!(value, Extend | Format | Zwj)
+}
+
+/// есть ли граница слов перед кодпоинтом i
+fn is_word_boundary(dataset: &Dataset, chars: &[(usize, u32, WordBreak)], i: usize) -> bool
+{
+    let class = |j: usize| chars[j].2;
+    let previous = |j: usize| (0 .. j).rev().find(|&k| !is_ignored(class(k)));
+    let next = |j: usize| (j + 1 .. chars.len()).find(|&k| !is_ignored(class(k)));
+
+    match (class(i - 1), class(i)) {
+        (Cr, Lf) => return false,
+        (Newline | Cr | Lf, _) | (_, Newline | Cr | Lf) => return true,
+        (Zwj, _) if dataset.has_property(chars[i].1, BinaryProperty::ExtendedPictographic) => {
+            return false
+        }
+        (WSegSpace, WSegSpace) => return false,
+        (_, Extend | Format | Zwj) => return false,
+        _ => (),
+    }
+
+    // WB4: расширяющие символы относятся к предыдущему кодпоинту, кроме начала строки и Newline
+    let left = match previous(i) {
+        Some(j) if !matches!(class(j), Newline | Cr | Lf) => j,
+        _ => i - 1,
+    };
+
+    let (l, r) = (class(left), class(i));
+    let l2 = previous(left).map(class);
+    let r2 = next(i).map(class);
+
+    let is_letter = |value: Option<WordBreak>| value.is_some_and(|value| value.is_letter());
+    let mid_letter = |value: WordBreak| matches!(value, MidLetter | MidNumLet | SingleQuote);
+    let mid_num = |value: WordBreak| matches!(value, MidNum | MidNumLet | SingleQuote);
+
+    match (l, r) {
+        _ if l.is_letter() && r.is_letter() => false,
+        _ if l.is_letter() && mid_letter(r) && is_letter(r2) => false,
+        _ if mid_letter(l) && r.is_letter() && is_letter(l2) => false,
+        (HebrewLetter, SingleQuote) => false,
+        (HebrewLetter, DoubleQuote) if r2 == Some(HebrewLetter) => false,
+        (DoubleQuote, HebrewLetter) if l2 == Some(HebrewLetter) => false,
+        (Numeric, Numeric) => false,
+        _ if l.is_letter() && r == Numeric => false,
+        _ if l == Numeric && r.is_letter() => false,
+        _ if mid_num(l) && r == Numeric && l2 == Some(Numeric) => false,
+        _ if l == Numeric && mid_num(r) && r2 == Some(Numeric) => false,
+        (Katakana, Katakana) => false,
+        (ALetter | HebrewLetter | Numeric | Katakana | ExtendNumLet, ExtendNumLet) => false,
+        (ExtendNumLet, ALetter | HebrewLetter | Numeric | Katakana) => false,
+        (RegionalIndicator, RegionalIndicator) => {
+            let mut count = 0;
+            let mut j = Some(left);
+
+            while let Some(k) = j.filter(|&k| class(k) == RegionalIndicator) {
+                count += 1;
+                j = previous(k);
+            }
+
+            count % 2 == 0
+        }
+        _ => true,
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "embedded")]
+
+use unicode_data::{
+    canonical_caseless_eq, case_fold, compatibility_caseless_eq, identifier_caseless_eq, to_lower,
+    to_title, to_upper, CaseMapper, UnicodeVersion,
+};
+
+#[test]
+fn simple_case_mapping()
+{
+    assert_eq!(to_upper("abc xyz ÿ"), "ABC XYZ Ÿ");
+    assert_eq!(to_lower("ABC XYZ Ÿ"), "abc xyz ÿ");
+    assert_eq!(to_title("hello wORLD, it's"), "Hello World, It's");
+
+    // границы слов UAX #29: одна точка между буквами не разделяет слово, две - разделяют
+    assert_eq!(to_title("e.g. hello..wORLD"), "E.g. Hello..World");
+    assert_eq!(to_title("3.14abc 'quoted'"), "3.14Abc 'Quoted'");
+}
+
+#[test]
+fn special_casing()
+{
+    let mapper = CaseMapper::new(UnicodeVersion::default().dataset());
+
+    assert_eq!(mapper.to_upper("straße"), "STRASSE");
+    assert_eq!(mapper.to_title("ßa"), "Ssa");
+    assert_eq!(mapper.to_upper("ﬁne"), "FINE");
+    assert_eq!(mapper.to_title("ﬁne ŉ"), "Fine ʼN");
+    assert_eq!(mapper.to_lower("\u{0130}"), "i\u{0307}");
+}
+
+#[test]
+fn final_sigma()
+{
+    let mapper = CaseMapper::new(UnicodeVersion::default().dataset());
+
+    assert_eq!(mapper.to_lower("ΟΔΟΣ"), "οδος");
+    assert_eq!(mapper.to_lower("ΟΔΟΣ ΣΑΣ."), "οδος σας.");
+    assert_eq!(mapper.to_lower("Σ"), "σ");
+    assert_eq!(mapper.to_lower("ΑΣ\u{0301}Α"), "ασ\u{0301}α");
+    assert_eq!(mapper.to_lower("ΑΣ\u{0301}"), "ας\u{0301}");
+}
+
+#[test]
+fn language_specific_casing()
+{
+    let turkish = CaseMapper::new(UnicodeVersion::default().dataset()).language("tr-TR");
+
+    assert_eq!(turkish.to_upper("i"), "\u{0130}");
+    assert_eq!(turkish.to_lower("I"), "ı");
+    assert_eq!(turkish.to_lower("I\u{0307}"), "i");
+    assert_eq!(turkish.to_lower("\u{0130}"), "i");
+    assert_eq!(turkish.to_title("istanbul"), "\u{0130}stanbul");
+
+    let lithuanian = CaseMapper::new(UnicodeVersion::default().dataset()).language("lt");
+
+    assert_eq!(lithuanian.to_lower("Ì"), "i\u{0307}\u{0300}");
+    assert_eq!(lithuanian.to_lower("I\u{0301}"), "i\u{0307}\u{0301}");
+    assert_eq!(lithuanian.to_lower("I\u{0328}"), "i\u{0328}");
+    assert_eq!(lithuanian.to_upper("i\u{0307}\u{0301}"), "I\u{0301}");
+
+    let neutral = CaseMapper::new(UnicodeVersion::default().dataset());
+
+    assert_eq!(neutral.to_upper("i"), "I");
+    assert_eq!(neutral.to_lower("I\u{0301}"), "i\u{0301}");
+    assert_eq!(neutral.to_upper("i\u{0307}"), "I\u{0307}");
+}
+
+#[test]
+fn case_folding()
+{
+    let mapper = CaseMapper::new(UnicodeVersion::default().dataset());
+
+    assert_eq!(mapper.case_fold("Straße"), "strasse");
+    assert_eq!(mapper.case_fold("ΣΑΣ"), "σασ");
+    assert_eq!(mapper.case_fold("\u{1E9E}"), "ss");
+    assert_eq!(mapper.simple_case_fold("\u{1E9E}"), "ß");
+    assert_eq!(mapper.case_fold("I\u{0130}"), "ii\u{0307}");
+
+    let turkish = CaseMapper::new(UnicodeVersion::default().dataset()).language("az");
+
+    assert_eq!(turkish.case_fold("I\u{0130}"), "ıi");
+}
+
+#[test]
+fn default_dataset_casing()
+{
+    assert_eq!(to_upper("ß"), "SS");
+    assert_eq!(to_upper("Straße"), "STRASSE");
+    assert_eq!(to_lower("ΟΔΟΣ"), "οδος");
+    assert_eq!(to_title("ﬁne"), "Fine");
+
+    assert_eq!(case_fold("ABC"), "abc");
+    assert_eq!(case_fold("ß"), "ss");
+    assert_eq!(case_fold("ſ"), "s");
+    assert_eq!(case_fold("ΣΑΣ"), "σασ");
+
+    let dataset = UnicodeVersion::default().dataset();
+
+    assert!(dataset.is_cased(0x41));
+    assert!(dataset.is_cased(0x02B0));
+    assert!(!dataset.is_cased(0x30));
+    assert!(dataset.is_case_ignorable(0x27));
+    assert!(dataset.is_case_ignorable(0x0301));
+    assert!(!dataset.is_case_ignorable(0x41));
+
+    // в 15.1.0 - те же отображения
+    let mapper = CaseMapper::new(UnicodeVersion::V15_1_0.dataset());
+
+    assert_eq!(mapper.to_upper("ß"), "SS");
+    assert_eq!(mapper.case_fold("ſ"), "s");
+    assert_eq!(mapper.to_lower("ΟΔΟΣ"), "οδος");
+}
+
+#[test]
+fn default_dataset_caseless_matching()
+{
+    assert!(canonical_caseless_eq("Admin", "admin"));
+    assert!(canonical_caseless_eq("ΣΑΣ", "σασ"));
+    assert!(canonical_caseless_eq("\u{212B}", "a\u{030A}"));
+    assert!(!canonical_caseless_eq("Admin", "admin1"));
+    assert!(!canonical_caseless_eq("\u{2460}", "1"));
+
+    assert!(compatibility_caseless_eq("Admin", "ADMIN"));
+    assert!(compatibility_caseless_eq("ﬁ", "FI"));
+    assert!(compatibility_caseless_eq("\u{2460}", "1"));
+
+    // ß / SS - полная свёртка из CaseFolding.txt
+    assert!(compatibility_caseless_eq("ß", "SS"));
+    assert!(canonical_caseless_eq("Straße", "STRASSE"));
+    assert!(canonical_caseless_eq("ſ", "S"));
+}
+
+#[test]
+fn canonical_caseless_matching()
+{
+    let mapper = CaseMapper::new(UnicodeVersion::default().dataset());
+
+    assert!(mapper.canonical_caseless_eq("\u{212B}", "å"));
+    assert!(mapper.canonical_caseless_eq("A\u{030A}", "\u{00C5}"));
+    assert!(mapper.canonical_caseless_eq("ΣΑΣ", "σας"));
+    assert!(mapper.canonical_caseless_eq("ᾈ", "ἀι"));
+    assert!(mapper.canonical_caseless_eq("ﬃ", "FFI"));
+    assert!(!mapper.canonical_caseless_eq("\u{2460}", "1"));
+    assert!(!mapper.canonical_caseless_eq("I", "ı"));
+
+    // U+0345 (CCC 240) перед U+0313 (CCC 230): без начальной NFD свёртка даст стартер U+03B9
+    // раньше U+0313, и строки не совпадут
+    assert!(mapper.canonical_caseless_eq("α\u{0345}\u{0313}", "ἀι"));
+    assert!(mapper.canonical_caseless_eq("α\u{0345}\u{0313}", "ᾀ"));
+
+    let turkish = CaseMapper::new(UnicodeVersion::default().dataset()).language("tr");
+
+    assert!(turkish.canonical_caseless_eq("I", "ı"));
+    assert!(turkish.canonical_caseless_eq("\u{0130}", "i"));
+}
+
+#[test]
+fn compatibility_caseless_matching()
+{
+    let mapper = CaseMapper::new(UnicodeVersion::default().dataset());
+
+    assert!(mapper.compatibility_caseless_eq("ﬃ", "FFI"));
+    assert!(mapper.compatibility_caseless_eq("\u{2460}", "1"));
+    assert!(mapper.compatibility_caseless_eq("\u{212B}", "a\u{030A}"));
+    assert!(mapper.compatibility_caseless_eq("α\u{0345}\u{0313}", "ἀι"));
+    assert!(!mapper.compatibility_caseless_eq("ﬃ", "FI"));
+}
+
+#[test]
+fn identifier_caseless_matching()
+{
+    assert!(identifier_caseless_eq("ﬁ", "FI"));
+    assert!(identifier_caseless_eq("Straße", "STRASSE"));
+    assert!(identifier_caseless_eq("\u{212B}", "a\u{030A}"));
+    assert!(identifier_caseless_eq("α\u{0345}\u{0313}", "ἀι"));
+    assert!(identifier_caseless_eq("A\u{00AD}B", "ab"));
+    assert!(!identifier_caseless_eq("ab", "a b"));
+}
@@ -0,0 +1,230 @@
+#![cfg(feature = "embedded")]
+
+use unicode_data::{
+    CollationTable, CollationTestFailureKind, CollationTestSuite, Collator, DatasetLoader,
+    FractionalUcaHeader, ParseErrorKind, SourceFile, Strength, UnicodeVersion, VariableWeighting,
+};
+
+fn check(suite: CollationTestSuite, version: UnicodeVersion)
+{
+    let report = suite.run(version.dataset());
+
+    assert!(report.passed > 0, "{}: {}", version, report);
+    assert!(report.is_ok(), "{}: {}", version, report);
+}
+
+#[test]
+fn ducet_non_ignorable()
+{
+    check(CollationTestSuite::DucetNonIgnorable, UnicodeVersion::V17_0_0);
+}
+
+#[test]
+fn ducet_shifted()
+{
+    check(CollationTestSuite::DucetShifted, UnicodeVersion::V17_0_0);
+}
+
+#[test]
+fn cldr_non_ignorable()
+{
+    for version in UnicodeVersion::ALL {
+        check(CollationTestSuite::CldrNonIgnorable, version);
+    }
+}
+
+#[test]
+fn cldr_shifted()
+{
+    for version in UnicodeVersion::ALL {
+        check(CollationTestSuite::CldrShifted, version);
+    }
+}
+
+#[test]
+fn key_failure()
+{
+    // строки полной версии теста, у второй неверный вес третьего уровня
+    let dataset = DatasetLoader::embedded(UnicodeVersion::default())
+        .text(
+            SourceFile::CollationTestDucetNonIgnorable,
+            "0061;\t# (a) LATIN SMALL LETTER A\t[23EC | 0020 | 0002 |]\n\
+             0041;\t# (A) LATIN CAPITAL LETTER A\t[23EC | 0020 | 0002 |]\n",
+        )
+        .load();
+
+    let report = CollationTestSuite::DucetNonIgnorable.run(&dataset);
+
+    assert_eq!(report.passed, 1);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].line, 2);
+    assert_eq!(
+        report.failures[0].kind,
+        CollationTestFailureKind::Key {
+            expected: vec![0x23EC, 0, 0x0020, 0, 0x0002],
+            actual: vec![0x23EC, 0, 0x0020, 0, 0x0008],
+        }
+    );
+    assert_eq!(
+        report.to_string(),
+        "CollationTest/CollationTest_NON_IGNORABLE.txt: пройдено 1, ошибок 1\n\
+         2: 0041 # (A) LATIN CAPITAL LETTER A\n\
+         \x20 ключ:\n\
+         \x20 - [23EC | 0020 | 0002]\n\
+         \x20 + [23EC | 0020 | 0008]\n\
+         \x20 путь: 0041 [.23EC.0020.0008]\n"
+    );
+}
+
+#[test]
+fn missing_collation_tests()
+{
+    // тестов сопоставлений DUCET 15.1.0 во встроенных данных нет
+    let dataset = UnicodeVersion::V15_1_0.dataset();
+    let error = CollationTestSuite::DucetNonIgnorable
+        .try_run(dataset)
+        .unwrap_err();
+
+    assert_eq!(error.file, SourceFile::CollationTestDucetNonIgnorable);
+    assert_eq!(error.kind, ParseErrorKind::MissingSource);
+}
+
+#[test]
+fn variable_weighting_sort_keys()
+{
+    // пример из UTS #10, 4.1: пробел [*0209.0020.0002], A [.23EC.0020.0008],
+    // COMBINING GRAVE ACCENT [.0000.0025.0002] (веса allkeys.txt 17.0.0)
+    let collator = Collator::new(UnicodeVersion::default().dataset(), CollationTable::Ducet)
+        .strength(Strength::Quaternary);
+
+    let keys = |variable_weighting| {
+        let collator = collator.variable_weighting(variable_weighting);
+
+        [" ", "A", " \u{300}", "A\u{300}"].map(|text| collator.sort_key(text))
+    };
+
+    assert_eq!(
+        keys(VariableWeighting::NonIgnorable),
+        [
+            vec![0x0209, 0, 0x0020, 0, 0x0002, 0, 0xFFFF],
+            vec![0x23EC, 0, 0x0020, 0, 0x0008, 0, 0xFFFF],
+            vec![0x0209, 0, 0x0020, 0x0025, 0, 0x0002, 0x0002, 0, 0xFFFF, 0xFFFF],
+            vec![0x23EC, 0, 0x0020, 0x0025, 0, 0x0008, 0x0002, 0, 0xFFFF, 0xFFFF],
+        ]
+    );
+    assert_eq!(
+        keys(VariableWeighting::Shifted),
+        [
+            vec![0, 0, 0, 0x0209],
+            vec![0x23EC, 0, 0x0020, 0, 0x0008, 0, 0xFFFF],
+            vec![0, 0, 0, 0x0209],
+            vec![0x23EC, 0, 0x0020, 0x0025, 0, 0x0008, 0x0002, 0, 0xFFFF, 0xFFFF],
+        ]
+    );
+    assert_eq!(
+        keys(VariableWeighting::Blanked),
+        [
+            vec![0, 0, 0],
+            vec![0x23EC, 0, 0x0020, 0, 0x0008, 0],
+            vec![0, 0, 0],
+            vec![0x23EC, 0, 0x0020, 0x0025, 0, 0x0008, 0x0002, 0],
+        ]
+    );
+    assert_eq!(
+        keys(VariableWeighting::ShiftTrimmed),
+        [
+            vec![0, 0, 0, 0x0209],
+            vec![0x23EC, 0, 0x0020, 0, 0x0008, 0],
+            vec![0, 0, 0, 0x0209],
+            vec![0x23EC, 0, 0x0020, 0x0025, 0, 0x0008, 0x0002, 0],
+        ]
+    );
+}
+
+#[test]
+fn implicit_collation_elements()
+{
+    let dataset = UnicodeVersion::default().dataset();
+    let implicit = |code| {
+        dataset
+            .implicit_collation_elements(code)
+            .map(|weights| (weights.l1, weights.l2, weights.l3))
+    };
+
+    // основные иероглифы CJK: 4E00..9FFF, F900..FAFF
+    assert_eq!(implicit(0x4E00), [(0xFB40, 0x0020, 0x0002), (0xCE00, 0, 0)]);
+    assert_eq!(implicit(0xFA0E), [(0xFB41, 0x0020, 0x0002), (0xFA0E, 0, 0)]);
+
+    // остальные унифицированные иероглифы
+    assert_eq!(implicit(0x3400), [(0xFB80, 0x0020, 0x0002), (0xB400, 0, 0)]);
+    assert_eq!(implicit(0x20000), [(0xFB84, 0x0020, 0x0002), (0x8000, 0, 0)]);
+
+    // неназначенные кодпоинты и иероглифы совместимости с декомпозицией
+    assert_eq!(implicit(0x0378), [(0xFBC0, 0x0020, 0x0002), (0x8378, 0, 0)]);
+    assert_eq!(implicit(0xF900), [(0xFBC1, 0x0020, 0x0002), (0xF900, 0, 0)]);
+    assert_eq!(implicit(0x10FFFF), [(0xFBE1, 0x0020, 0x0002), (0xFFFF, 0, 0)]);
+
+    // @implicitweights: смещение отсчитывается от 17000 и для Tangut Supplement
+    assert_eq!(implicit(0x17000), [(0xFB00, 0x0020, 0x0002), (0x8000, 0, 0)]);
+    assert_eq!(implicit(0x187F7), [(0xFB00, 0x0020, 0x0002), (0x97F7, 0, 0)]);
+    assert_eq!(implicit(0x18D00), [(0xFB00, 0x0020, 0x0002), (0x9D00, 0, 0)]);
+    assert_eq!(implicit(0x1B170), [(0xFB02, 0x0020, 0x0002), (0x8000, 0, 0)]);
+}
+
+#[test]
+fn fractional_uca_formatted()
+{
+    let sources = [
+        include_str!("../data/cldr 48/FractionalUCA_SHORT.txt"),
+        include_str!("../data/cldr 44/FractionalUCA_SHORT.txt"),
+        include_str!("../data/cldr 44/FractionalUCA.txt"),
+    ];
+
+    for source in sources {
+        let dataset = DatasetLoader::new()
+            .text(SourceFile::FractionalUca, source)
+            .load();
+
+        let expected: String = source
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| format!("{}\n", line))
+            .collect();
+
+        assert_eq!(dataset.fractional_uca().formatted(), expected);
+    }
+}
+
+#[test]
+fn fractional_uca_han_primaries()
+{
+    let table = UnicodeVersion::default().dataset().fractional_uca();
+
+    // [radical 1=⼀一:一𪛙丁-丆...], первый праймари группы Hani - [81 02 02] (HAN first primary)
+    assert_eq!(table.han_primary(0x4E00), Some(vec![0x81, 0x02, 0x03]));
+    assert_eq!(table.han_primary(0x2A6D9), Some(vec![0x81, 0x02, 0x04]));
+    assert_eq!(table.han_primary(0x4E01), Some(vec![0x81, 0x02, 0x05]));
+    assert_eq!(table.han_primary(0x4E06), Some(vec![0x81, 0x02, 0x0A]));
+    assert_eq!(table.han_primary(0x0041), None);
+
+    let codes: Vec<u32> = table
+        .header
+        .iter()
+        .flat_map(|record| match record {
+            FractionalUcaHeader::Radical(radical) => radical.codes.as_slice(),
+            _ => &[],
+        })
+        .flat_map(|&(first, last)| first ..= last)
+        .collect();
+
+    // третий байт - 02 ..= FF: 254-й иероглиф переносится во второй байт
+    assert_eq!(table.han_primary(codes[253]), Some(vec![0x81, 0x03, 0x02]));
+
+    let primaries: Vec<Vec<u8>> = codes
+        .iter()
+        .filter_map(|&code| table.han_primary(code))
+        .collect();
+
+    assert_eq!(primaries.len(), codes.len());
+    assert!(primaries.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,320 @@
+#![cfg(feature = "embedded")]
+
+use std::cell::Cell;
+use std::iter;
+
+use unicode_data::{
+    canonical_equivalents, fcc, is_fcd, is_normalized_quick, is_stream_safe, nfc, nfd, nfkc,
+    nfkc_casefold, nfkc_simple_casefold, nfkd, stream_safe, NormalizationForm,
+    NormalizationProperty, Normalizer, QuickCheck, UnicodeVersion, CANONICAL_CLOSURE,
+    MAX_CANONICAL_EQUIVALENTS,
+};
+
+#[test]
+fn normalization_tests()
+{
+    for version in UnicodeVersion::ALL {
+        let report = version.dataset().normalization_conformance();
+
+        assert!(report.passed > 0, "{}: {}", version.as_str(), report);
+        assert!(report.is_ok(), "{}: {}", version.as_str(), report);
+    }
+}
+
+#[test]
+fn string_normalizer()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    for test in dataset.normalization_tests() {
+        for source in [&test.c1, &test.c2, &test.c3] {
+            assert_eq!(nfc(source), test.c2, "{:?}", test);
+            assert_eq!(nfd(source), test.c3, "{:?}", test);
+        }
+
+        for source in [&test.c1, &test.c2, &test.c3, &test.c4, &test.c5] {
+            assert_eq!(nfkc(source), test.c4, "{:?}", test);
+            assert_eq!(nfkd(source), test.c5, "{:?}", test);
+        }
+    }
+}
+
+#[test]
+fn quick_check()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    for test in dataset.normalization_tests() {
+        for source in [&test.c1, &test.c2, &test.c3, &test.c4, &test.c5] {
+            for form in NormalizationForm::ALL {
+                let normalized = Normalizer::new(dataset, form).normalize(source) == *source;
+
+                match is_normalized_quick(source, form) {
+                    QuickCheck::Yes => assert!(normalized, "{}: {:?}", form, test),
+                    QuickCheck::No => assert!(!normalized, "{}: {:?}", form, test),
+                    QuickCheck::Maybe => (),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn streaming_normalizer()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    for test in dataset.normalization_tests() {
+        for form in NormalizationForm::ALL {
+            let normalizer = Normalizer::new(dataset, form);
+            let streamed: String = normalizer.normalize_chars(test.c1.chars()).collect();
+
+            assert_eq!(
+                streamed,
+                normalizer.normalize(&test.c1),
+                "{}: {:?}",
+                form,
+                test
+            );
+        }
+    }
+
+    // 'a' + 40 x U+0301: в буфере не более 30 нестартеров, далее - CGJ
+    let text: String = ['a'].into_iter().chain(['\u{301}'; 40]).collect();
+    let normalizer = Normalizer::new(dataset, NormalizationForm::Nfc);
+    let streamed: String = normalizer.normalize_chars(text.chars()).collect();
+
+    let expected: String = ['\u{E1}']
+        .into_iter()
+        .chain(['\u{301}'; 29])
+        .chain(['\u{34F}'])
+        .chain(['\u{301}'; 10])
+        .collect();
+
+    assert_eq!(streamed, expected);
+}
+
+#[test]
+fn streaming_normalizer_bounded_buffer()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    // U+1100 + U+1161 = U+AC00, следующие U+1161 не комбинируются со слогом LV - каждый из них
+    // выдаётся сразу, не дожидаясь конца бесконечного входа
+    for form in [NormalizationForm::Nfc, NormalizationForm::Nfkc] {
+        let consumed = Cell::new(0);
+        let chars = iter::once('\u{1100}')
+            .chain(iter::repeat('\u{1161}'))
+            .inspect(|_| consumed.set(consumed.get() + 1));
+
+        let normalizer = Normalizer::new(dataset, form);
+        let streamed: String = normalizer.normalize_chars(chars).take(1000).collect();
+
+        let expected: String = iter::once('\u{AC00}')
+            .chain(iter::repeat_n('\u{1161}', 999))
+            .collect();
+
+        assert_eq!(streamed, expected, "{}", form);
+        assert!(consumed.get() <= 1002, "{}: {}", form, consumed.get());
+    }
+
+    // то же для конечного входа: результат совпадает с нормализацией строки
+    let text: String = iter::once('\u{1100}')
+        .chain(iter::repeat_n('\u{1161}', 200_000))
+        .collect();
+    let normalizer = Normalizer::new(dataset, NormalizationForm::Nfc);
+    let streamed: String = normalizer.normalize_chars(text.chars()).collect();
+
+    assert_eq!(streamed, normalizer.normalize(&text));
+    assert_eq!(streamed.chars().count(), 200_000);
+}
+
+#[test]
+fn stream_safe_text_format()
+{
+    // U+0344 раскладывается на два нестартера: 15 x 2 = 30, шестнадцатому предшествует CGJ
+    let text: String = ['a'].into_iter().chain(['\u{344}'; 20]).collect();
+    let safe = stream_safe(&text);
+
+    assert!(!is_stream_safe(&text));
+    assert!(is_stream_safe(&safe));
+    assert_eq!(safe.chars().position(|c| c == '\u{34F}'), Some(16));
+    assert_eq!(safe.chars().count(), text.chars().count() + 1);
+    assert_eq!(stream_safe(&safe), safe);
+
+    let dataset = UnicodeVersion::default().dataset();
+
+    for form in NormalizationForm::ALL {
+        let normalizer = Normalizer::new(dataset, form);
+        let streamed: String = normalizer.normalize_chars(text.chars()).collect();
+
+        assert_eq!(streamed, normalizer.normalize(&safe), "{}", form);
+    }
+}
+
+#[test]
+fn fcd_and_fcc()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    for test in dataset.normalization_tests() {
+        for source in [&test.c1, &test.c2, &test.c3, &test.c4, &test.c5] {
+            let composed = fcc(source);
+
+            assert!(is_fcd(&composed), "{:?}", test);
+            assert_eq!(nfd(&composed), nfd(source), "{:?}", test);
+            assert_eq!(fcc(&composed), composed, "{:?}", test);
+
+            // FCD: посимвольная NFD-декомпозиция уже канонически упорядочена
+            let decomposed: String = source.chars().map(|c| nfd(&c.to_string())).collect();
+            assert_eq!(is_fcd(source), decomposed == nfd(source), "{:?}", test);
+        }
+    }
+
+    // в FCC комбинируются только соседние кодпоинты
+    assert_eq!(nfc("a\u{327}\u{301}"), "\u{E1}\u{327}");
+    assert_eq!(fcc("a\u{327}\u{301}"), "a\u{327}\u{301}");
+    assert!(!is_fcd("\u{E1}\u{327}"));
+
+    // быстрая проверка FCC: NFC_QC и FCD
+    let fcc_quick = |text| is_normalized_quick(text, NormalizationForm::Fcc);
+
+    assert_eq!(fcc_quick("abc"), QuickCheck::Yes);
+    assert_eq!(fcc_quick("\u{E1}\u{327}"), QuickCheck::No);
+    assert_eq!(fcc_quick("\u{212B}"), QuickCheck::No);
+    assert_eq!(fcc_quick("a\u{301}"), QuickCheck::Maybe);
+    assert_eq!(fcc_quick("a\u{327}\u{301}"), QuickCheck::Maybe);
+}
+
+#[test]
+fn canonical_closure()
+{
+    let equivalents = canonical_equivalents("\u{1E09}");
+
+    for expected in [
+        "c\u{327}\u{301}",
+        "c\u{301}\u{327}",
+        "\u{E7}\u{301}",
+        "\u{107}\u{327}",
+        "\u{1E09}",
+    ] {
+        assert!(equivalents.iter().any(|s| s == expected), "{:?}", expected);
+    }
+
+    for equivalent in &equivalents {
+        assert_eq!(nfd(equivalent), nfd("\u{1E09}"));
+    }
+
+    assert_eq!(canonical_equivalents("\u{AC01}").len(), 3);
+    assert_eq!(CANONICAL_CLOSURE.get(&[0x41, 0x30A]), &[0xC5, 0x212B]);
+
+    // 10 нестартеров с разными CCC - больше 10! перестановок, результат ограничен
+    let text = "a\u{334}\u{5B0}\u{5B1}\u{5B2}\u{5B3}\u{327}\u{31B}\u{323}\u{301}\u{345}";
+    let equivalents = canonical_equivalents(text);
+
+    assert_eq!(equivalents.len(), MAX_CANONICAL_EQUIVALENTS);
+
+    for equivalent in &equivalents {
+        assert_eq!(nfd(equivalent), nfd(text));
+    }
+
+    // одинаковые нестартеры: без запоминания остатков - n! путей перебора
+    let text = format!("a{}", "\u{308}".repeat(12));
+    let equivalents = canonical_equivalents(&text);
+
+    assert_eq!(
+        equivalents,
+        [text.clone(), format!("\u{E4}{}", "\u{308}".repeat(11))]
+    );
+
+    // перестановки 4 + 4 нестартеров с разными CCC: o - 70, ö, ǫ - по 35
+    let text = format!("o{}{}", "\u{328}".repeat(4), "\u{308}".repeat(4));
+    let equivalents = canonical_equivalents(&text);
+
+    assert_eq!(equivalents.len(), 140);
+
+    for equivalent in &equivalents {
+        assert_eq!(nfd(equivalent), nfd(&text));
+    }
+}
+
+#[test]
+fn nfkc_casefold_mapping()
+{
+    assert_eq!(
+        nfkc_casefold("Straße ǅ ﬁ Ⅻ A\u{AD}B"),
+        "strasse dž fi xii ab"
+    );
+    assert_eq!(nfkc_simple_casefold("Straße ǅ ﬁ"), "straße dž fi");
+
+    // отображения отдельных кодпоинтов могут комбинироваться: A + U+030A -> å
+    assert_eq!(nfkc_casefold("A\u{30A}"), "\u{E5}");
+
+    let dataset = UnicodeVersion::default().dataset();
+
+    for code in [0x41, 0xAD, 0x2160, 0x61, 0x3042] {
+        let changes = dataset.nfkc_casefold_mapping(code) != [code];
+        assert_eq!(
+            dataset.changes_when_nfkc_casefolded(code),
+            changes,
+            "{:04X}",
+            code
+        );
+    }
+}
+
+#[test]
+fn derived_normalization_properties()
+{
+    for version in UnicodeVersion::ALL {
+        let dataset = version.dataset();
+        let ccc = |code: u32| dataset.unicode().get(&code).map_or(0, |c| c.ccc.u8());
+
+        for codepoint in dataset.unicode().values() {
+            // исключения композиции + синглтоны + декомпозиции, начинающиеся с нестартера
+            let excluded = codepoint.has_canonical_decomposition()
+                && (codepoint.is_composition_exclusion_in(dataset)
+                    || codepoint.decomposition.len() == 1
+                    || codepoint.is_nonstarter()
+                    || ccc(codepoint.decomposition[0]) != 0);
+
+            assert_eq!(
+                codepoint.is_full_composition_exclusion_in(dataset),
+                excluded,
+                "{}: {:04X}",
+                version.as_str(),
+                codepoint.code
+            );
+        }
+
+        for c in dataset
+            .unicode()
+            .keys()
+            .filter_map(|&code| char::from_u32(code))
+        {
+            for (form, property) in [
+                (NormalizationForm::Nfd, NormalizationProperty::ExpandsOnNfd),
+                (NormalizationForm::Nfc, NormalizationProperty::ExpandsOnNfc),
+                (
+                    NormalizationForm::Nfkd,
+                    NormalizationProperty::ExpandsOnNfkd,
+                ),
+                (
+                    NormalizationForm::Nfkc,
+                    NormalizationProperty::ExpandsOnNfkc,
+                ),
+            ] {
+                let normalized = Normalizer::new(dataset, form).normalize(&c.to_string());
+
+                assert_eq!(
+                    dataset.has_normalization_property(u32::from(c), property),
+                    normalized.chars().count() != 1,
+                    "{}: {:04X} {}",
+                    version.as_str(),
+                    u32::from(c),
+                    form
+                );
+            }
+        }
+    }
+}
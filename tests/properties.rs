@@ -0,0 +1,423 @@
+#![cfg(feature = "embedded")]
+
+use unicode_data::codepoint::{BinaryProperty, IndicConjunctBreak, PropertiesError, Script};
+use unicode_data::{
+    get_indic_conjunct_break, get_script, get_script_extensions, has_property,
+    property_file_records, DatasetLoader, ParseErrorKind, SourceFile, UnicodeVersion, UNICODE,
+};
+
+const SCRIPTS: &str = include_str!("../data/ucd 17.0.0/Scripts.txt");
+const DERIVED_CORE_PROPERTIES: &str =
+    include_str!("../data/ucd 17.0.0/DerivedCoreProperties.txt");
+
+#[test]
+fn script_names()
+{
+    assert_eq!(Script::ALL.len(), 177);
+
+    for &script in Script::ALL {
+        assert_eq!(Script::try_from(script.abbr()), Ok(script));
+        assert_eq!(Script::try_from(script.as_str()), Ok(script));
+    }
+
+    assert_eq!(Script::try_from("Latn"), Ok(Script::Latin));
+    assert_eq!(Script::try_from("Old_Permic"), Ok(Script::OldPermic));
+    assert_eq!(Script::try_from("Qaai"), Ok(Script::Inherited));
+    assert_eq!(Script::Common.abbr(), "Zyyy");
+    assert!(Script::try_from("Klingon").is_err());
+}
+
+#[test]
+fn scripts()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    assert_eq!(dataset.script(0x41), Script::Latin);
+    assert_eq!(dataset.script(0x0430), Script::Cyrillic);
+    assert_eq!(dataset.script(0x0301), Script::Inherited);
+    assert_eq!(dataset.script(0x20), Script::Common);
+    assert_eq!(dataset.script(0x4E00), Script::Han);
+    assert_eq!(dataset.script(0x0378), Script::Unknown);
+    assert_eq!(dataset.script(0x10FFFF), Script::Unknown);
+
+    assert_eq!(dataset.script_extensions(0x41), [Script::Latin]);
+    assert_eq!(
+        dataset.script_extensions(0x0301),
+        [
+            Script::Cherokee,
+            Script::Cyrillic,
+            Script::Greek,
+            Script::Latin,
+            Script::Osage,
+            Script::Sunuwar,
+            Script::TaiLe,
+            Script::Todhri
+        ]
+    );
+    assert_eq!(dataset.script_extensions(0x0316), [Script::Inherited]);
+    assert_eq!(dataset.script_extensions(0x0363), [Script::Latin]);
+    assert_eq!(
+        dataset.script_extensions(0x0483),
+        [Script::Cyrillic, Script::OldPermic]
+    );
+    assert_eq!(
+        dataset.script_extensions(0x30FC),
+        [Script::Hiragana, Script::Katakana]
+    );
+
+    let codepoint = &dataset.unicode()[&0x0484];
+
+    assert_eq!(codepoint.script(dataset), Script::Cyrillic);
+    assert_eq!(
+        codepoint.script_extensions(dataset),
+        [Script::Cyrillic, Script::Glagolitic]
+    );
+
+    assert_eq!(get_script(0x41), Script::Latin);
+    assert_eq!(get_script(0x4E00), Script::Han);
+    assert_eq!(
+        get_script_extensions(0x30FC),
+        [Script::Hiragana, Script::Katakana]
+    );
+
+    // письменности Beria Erfe в 15.1.0 ещё нет
+    let dataset = UnicodeVersion::V15_1_0.dataset();
+
+    assert_eq!(dataset.script(0x41), Script::Latin);
+    assert_eq!(dataset.script(0x4E00), Script::Han);
+    assert_eq!(dataset.script(0x16EA0), Script::Unknown);
+    assert_eq!(
+        UnicodeVersion::V17_0_0.dataset().script(0x16EA0),
+        Script::BeriaErfe
+    );
+}
+
+#[test]
+fn scripts_parse_error()
+{
+    let dataset = DatasetLoader::new()
+        .text(
+            SourceFile::Scripts,
+            "0041..005A ; Latin\n0061 ; Klingon # ?\n",
+        )
+        .load();
+
+    let error = dataset.try_scripts().unwrap_err();
+
+    assert_eq!(error.file, SourceFile::Scripts);
+    assert_eq!(error.line, 2);
+    assert!(matches!(error.kind, ParseErrorKind::Property(_)));
+}
+
+#[test]
+fn property_file_records_with_missing()
+{
+    let dataset = UnicodeVersion::default().dataset();
+    let records: Vec<_> = property_file_records(SourceFile::Scripts, SCRIPTS)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert!(records.contains(&(0x0041 ..= 0x005A, vec!["Latin"])));
+
+    // значения по умолчанию - для диапазонов, не перечисленных явно
+    let defaults: Vec<_> = records
+        .iter()
+        .filter(|(_, fields)| fields == &["Unknown"])
+        .map(|(range, _)| range.clone())
+        .collect();
+
+    assert!(defaults.contains(&(0x0378 ..= 0x0379)));
+    assert!(defaults.iter().any(|range| *range.end() == 0x10FFFF));
+
+    for range in defaults {
+        assert!(range.into_iter().all(|code| dataset.script(code) == Script::Unknown));
+    }
+
+    // @missing с названием свойства действует только на записи этого свойства
+    let file = SourceFile::DerivedCoreProperties;
+    let records: Vec<_> = property_file_records(file, DERIVED_CORE_PROPERTIES)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    let defaults: Vec<_> = records
+        .iter()
+        .filter(|(_, fields)| fields == &["InCB", "None"])
+        .map(|(range, _)| range.clone())
+        .collect();
+
+    assert!(defaults.contains(&(0x0000 ..= 0x02FF)));
+    assert!(!defaults.iter().any(|range| range.contains(&0x094D)));
+
+    for range in defaults {
+        assert!(range
+            .into_iter()
+            .all(|code| dataset.indic_conjunct_break(code) == IndicConjunctBreak::None));
+    }
+
+    let error = property_file_records(SourceFile::Scripts, "0041..005A ; Latin\nXYZ ; Latin\n")
+        .find_map(Result::err)
+        .unwrap();
+
+    assert_eq!(error.line, 2);
+    assert_eq!(error.kind, ParseErrorKind::InvalidHex);
+}
+
+#[test]
+fn invalid_ranges()
+{
+    let dataset = DatasetLoader::new()
+        .text(
+            SourceFile::Scripts,
+            "0041..005A ; Latin\n007A..0061 ; Latin\n",
+        )
+        .load();
+
+    let error = dataset.try_scripts().unwrap_err();
+
+    assert_eq!(error.line, 2);
+    assert_eq!(error.text, "007A..0061");
+    assert!(matches!(error.kind, ParseErrorKind::Malformed(_)));
+
+    let dataset = DatasetLoader::new()
+        .text(SourceFile::Scripts, "10FFFF..110000 ; Unknown\n")
+        .load();
+
+    assert_eq!(
+        dataset.try_scripts().unwrap_err().kind,
+        ParseErrorKind::OutOfRange
+    );
+
+    let dataset = DatasetLoader::new()
+        .text(SourceFile::Scripts, "110000 ; Unknown\n")
+        .load();
+
+    assert_eq!(
+        dataset.try_scripts().unwrap_err().kind,
+        ParseErrorKind::OutOfRange
+    );
+}
+
+#[test]
+fn binary_properties()
+{
+    for &property in BinaryProperty::ALL {
+        assert_eq!(BinaryProperty::try_from(property.abbr()), Ok(property));
+        assert_eq!(BinaryProperty::try_from(property.as_str()), Ok(property));
+    }
+
+    assert_eq!(
+        BinaryProperty::try_from("WSpace"),
+        Ok(BinaryProperty::WhiteSpace)
+    );
+
+    let dataset = UnicodeVersion::default().dataset();
+
+    assert!(dataset.has_property(0x41, BinaryProperty::Alphabetic));
+    assert!(dataset.has_property(0x0430, BinaryProperty::Alphabetic));
+    assert!(!dataset.has_property(0x30, BinaryProperty::Alphabetic));
+    assert!(dataset.has_property(0x20, BinaryProperty::WhiteSpace));
+    assert!(dataset.has_property(0x0B, BinaryProperty::WhiteSpace));
+    assert!(dataset.has_property(0x3000, BinaryProperty::WhiteSpace));
+    assert!(!dataset.has_property(0x41, BinaryProperty::WhiteSpace));
+    assert!(dataset.has_property(0x66, BinaryProperty::AsciiHexDigit));
+    assert!(!dataset.has_property(0x67, BinaryProperty::AsciiHexDigit));
+    assert!(dataset.has_property(0x2B, BinaryProperty::Math));
+    assert!(dataset.has_property(0x5F, BinaryProperty::IdContinue));
+    assert!(!dataset.has_property(0x5F, BinaryProperty::IdStart));
+    assert!(!dataset.has_property(0x41, BinaryProperty::Lowercase));
+    assert!(dataset.has_property(0x1F600, BinaryProperty::Emoji));
+    assert!(dataset.has_property(0x1F600, BinaryProperty::ExtendedPictographic));
+    assert!(!dataset.has_property(0x41, BinaryProperty::Emoji));
+
+    let codepoint = &dataset.unicode()[&0x00AD];
+
+    assert!(codepoint.has_property(dataset, BinaryProperty::DefaultIgnorableCodePoint));
+    assert!(!codepoint.has_property(dataset, BinaryProperty::Alphabetic));
+
+    assert!(has_property(0x41, BinaryProperty::Alphabetic));
+    assert!(has_property(0x20, BinaryProperty::WhiteSpace));
+
+    // Modifier_Combining_Mark появилось в 16.0.0
+    assert!(!UnicodeVersion::V15_1_0
+        .dataset()
+        .has_property(0x0654, BinaryProperty::ModifierCombiningMark));
+    assert!(UnicodeVersion::V17_0_0
+        .dataset()
+        .has_property(0x0654, BinaryProperty::ModifierCombiningMark));
+}
+
+#[test]
+fn binary_properties_without_source()
+{
+    let dataset = DatasetLoader::new()
+        .text(SourceFile::PropList, "0020 ; White_Space\n")
+        .load();
+
+    assert_eq!(
+        dataset.try_has_property(0x20, BinaryProperty::WhiteSpace),
+        Ok(true)
+    );
+    assert_eq!(
+        dataset.try_has_property(0x41, BinaryProperty::WhiteSpace),
+        Ok(false)
+    );
+
+    let error = dataset
+        .try_has_property(0x41, BinaryProperty::Alphabetic)
+        .unwrap_err();
+
+    assert_eq!(error.file, SourceFile::DerivedCoreProperties);
+    assert_eq!(error.text, "Alphabetic");
+    assert_eq!(error.kind, ParseErrorKind::MissingSource);
+
+    assert_eq!(
+        dataset
+            .try_has_property(0x1F600, BinaryProperty::Emoji)
+            .unwrap_err()
+            .kind,
+        ParseErrorKind::MissingSource
+    );
+}
+
+#[test]
+fn missing_sources()
+{
+    let dataset = DatasetLoader::new()
+        .text(SourceFile::Blocks, "0000..007F; Basic Latin\n")
+        .load();
+
+    let error = dataset.validate().unwrap_err();
+
+    assert_eq!(error.file, SourceFile::UnicodeData);
+    assert_eq!(error.line, 0);
+    assert_eq!(error.kind, ParseErrorKind::MissingSource);
+
+    assert!(dataset.try_blocks().is_ok());
+
+    for error in [
+        dataset.try_unicode().map(|_| ()).unwrap_err(),
+        dataset.try_ducet().map(|_| ()).unwrap_err(),
+        dataset.try_normalization_tests().map(|_| ()).unwrap_err(),
+        dataset.try_binary_properties().map(|_| ()).unwrap_err(),
+    ] {
+        assert_eq!(error.kind, ParseErrorKind::MissingSource);
+    }
+
+    assert_eq!(UnicodeVersion::V17_0_0.dataset().validate(), Ok(()));
+
+    // LineBreakTest.txt 15.1.0 во встроенных данных нет
+    let error = UnicodeVersion::V15_1_0.dataset().validate().unwrap_err();
+
+    assert_eq!(error.file, SourceFile::LineBreakTest);
+    assert_eq!(error.kind, ParseErrorKind::MissingSource);
+}
+
+#[test]
+fn unknown_property_names()
+{
+    for version in UnicodeVersion::ALL {
+        let dataset = version.dataset();
+
+        assert!(dataset.try_binary_properties().is_ok(), "{}", version.as_str());
+        assert!(dataset.try_normalization_properties().is_ok(), "{}", version.as_str());
+    }
+
+    let dataset = DatasetLoader::embedded(UnicodeVersion::default())
+        .text(SourceFile::DerivedNormalizationProps, "0041 ; Unknown_Property\n")
+        .load();
+
+    let error = dataset.try_normalization_properties().map(|_| ()).unwrap_err();
+
+    assert_eq!(error.file, SourceFile::DerivedNormalizationProps);
+    assert_eq!(error.line, 1);
+    assert_eq!(error.field, 1);
+    assert_eq!(error.text, "Unknown_Property");
+    assert!(matches!(error.kind, ParseErrorKind::Property(_)), "{}", error);
+
+    let dataset = DatasetLoader::embedded(UnicodeVersion::default())
+        .text(SourceFile::PropList, "0020 ; White_Space\n0041 ; Unknown_Property\n")
+        .load();
+
+    let error = dataset.try_has_property(0x20, BinaryProperty::WhiteSpace).unwrap_err();
+
+    assert_eq!(error.file, SourceFile::PropList);
+    assert_eq!(error.line, 2);
+    assert_eq!(error.field, 1);
+    assert_eq!(error.text, "Unknown_Property");
+    assert!(matches!(error.kind, ParseErrorKind::Property(_)), "{}", error);
+}
+
+#[test]
+fn properties_error()
+{
+    let parse = |text: &str| -> Result<u8, PropertiesError> { Ok(text.parse::<u8>()?) };
+
+    assert_eq!(parse("230"), Ok(230));
+    assert!(matches!(parse("256"), Err(PropertiesError::InvalidNumber(_))));
+    assert_eq!(
+        PropertiesError::unknown("Script", "Klingon").to_string(),
+        "неизвестное значение свойства Script: \"Klingon\""
+    );
+}
+
+#[test]
+fn indic_conjunct_break()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    assert_eq!(
+        dataset.indic_conjunct_break(0x094D),
+        IndicConjunctBreak::Linker
+    );
+    assert_eq!(
+        dataset.indic_conjunct_break(0x0915),
+        IndicConjunctBreak::Consonant
+    );
+    assert_eq!(
+        dataset.indic_conjunct_break(0x200D),
+        IndicConjunctBreak::Extend
+    );
+    assert_eq!(dataset.indic_conjunct_break(0x41), IndicConjunctBreak::None);
+    assert_eq!(
+        dataset.unicode()[&0x0301].indic_conjunct_break(dataset),
+        IndicConjunctBreak::Extend
+    );
+    assert_eq!(get_indic_conjunct_break(0x094D), IndicConjunctBreak::Linker);
+}
+
+#[test]
+fn codepoint_block_and_composition_exclusion()
+{
+    let qa = &UNICODE[&0x0958];
+
+    assert!(qa.is_composition_exclusion());
+    assert!(!UNICODE[&0x0915].is_composition_exclusion());
+    assert!(qa.is_full_composition_exclusion());
+    assert!(UNICODE[&0x0344].is_full_composition_exclusion());
+    assert!(!UNICODE[&0x0344].is_composition_exclusion());
+    assert!(!UNICODE[&0x0915].is_full_composition_exclusion());
+
+    for version in UnicodeVersion::ALL {
+        let dataset = version.dataset();
+        let qa = &dataset.unicode()[&0x0958];
+
+        assert_eq!(
+            qa.block_in(dataset).map(|block| block.name.as_str()),
+            Some("Devanagari")
+        );
+        assert!(qa.is_composition_exclusion_in(dataset));
+        assert!(!dataset.unicode()[&0x0915].is_composition_exclusion_in(dataset));
+        assert!(qa.is_full_composition_exclusion_in(dataset));
+    }
+
+    // блок определяется по Blocks.txt того набора данных, в котором ищется
+    let dataset = DatasetLoader::embedded(UnicodeVersion::default())
+        .text(SourceFile::Blocks, "0000..007F; Custom Latin\n")
+        .load();
+
+    assert_eq!(
+        dataset.unicode()[&0x41].block_in(&dataset).map(|block| block.name.as_str()),
+        Some("Custom Latin")
+    );
+    assert!(UNICODE[&0x0958].block_in(&dataset).is_none());
+}
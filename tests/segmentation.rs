@@ -0,0 +1,446 @@
+#![cfg(feature = "embedded")]
+
+use unicode_data::codepoint::{
+    BinaryProperty, EastAsianWidth, GeneralCategory, GraphemeClusterBreak, LineBreak,
+    SentenceBreak, WordBreak,
+};
+use unicode_data::{
+    get_east_asian_width, get_grapheme_cluster_break, get_line_break, get_sentence_break,
+    get_word_break, graphemes, line_breaks, sentences, words, BreakOpportunity, DatasetLoader,
+    ParseErrorKind, SourceFile, UnicodeVersion,
+};
+
+#[test]
+fn grapheme_cluster_break()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x0D),
+        GraphemeClusterBreak::Cr
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x0A),
+        GraphemeClusterBreak::Lf
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x0301),
+        GraphemeClusterBreak::Extend
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x200C),
+        GraphemeClusterBreak::Extend
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x0903),
+        GraphemeClusterBreak::SpacingMark
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x0600),
+        GraphemeClusterBreak::Prepend
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x00AD),
+        GraphemeClusterBreak::Control
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x1160),
+        GraphemeClusterBreak::V
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0xAC00),
+        GraphemeClusterBreak::Lv
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0xAC1B),
+        GraphemeClusterBreak::Lvt
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x1F1E6),
+        GraphemeClusterBreak::RegionalIndicator
+    );
+    assert_eq!(
+        dataset.grapheme_cluster_break(0x41),
+        GraphemeClusterBreak::Other
+    );
+    assert_eq!(
+        dataset.unicode()[&0x200D].grapheme_cluster_break(dataset),
+        GraphemeClusterBreak::Zwj
+    );
+    assert_eq!(
+        get_grapheme_cluster_break(0x1F3FF),
+        GraphemeClusterBreak::Extend
+    );
+
+    assert!(dataset.has_property(0x1F476, BinaryProperty::ExtendedPictographic));
+    assert!(dataset.has_property(0x1F3FF, BinaryProperty::EmojiModifier));
+    assert!(!dataset.has_property(0x41, BinaryProperty::ExtendedPictographic));
+}
+
+#[test]
+fn grapheme_break_tests()
+{
+    let dataset = UnicodeVersion::default().dataset();
+    let tests = dataset.grapheme_break_tests();
+
+    assert_eq!(tests.len(), 766);
+    assert_eq!(tests[0].line, 27);
+    assert_eq!(tests[1].boundaries, [0, 1, 3, 4]);
+    assert_eq!(tests[2].segments(), ["\r\n"]);
+
+    for version in [UnicodeVersion::V15_1_0, UnicodeVersion::V17_0_0] {
+        let dataset = version.dataset();
+        let report = dataset.grapheme_break_conformance();
+
+        assert_eq!(
+            report.passed,
+            dataset.grapheme_break_tests().len(),
+            "{}",
+            report
+        );
+        assert!(report.is_ok(), "{}", report);
+    }
+
+    assert_eq!(
+        UnicodeVersion::V15_1_0.dataset().grapheme_break_tests().len(),
+        1187
+    );
+}
+
+#[test]
+fn grapheme_break_tests_parse_error()
+{
+    let dataset = DatasetLoader::new()
+        .text(SourceFile::GraphemeBreakTest, "÷ 0020 ÷\n÷ 0020 ? 0308 ÷\n")
+        .load();
+
+    let error = dataset.try_grapheme_break_tests().unwrap_err();
+
+    assert_eq!(error.file, SourceFile::GraphemeBreakTest);
+    assert_eq!(error.line, 2);
+    assert_eq!(error.kind, ParseErrorKind::InvalidHex);
+}
+
+#[test]
+fn graphemes_iterator()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    let clusters: Vec<&str> = dataset.graphemes("e\u{0301}x\r\n🇷🇺🇩").collect();
+
+    assert_eq!(clusters, ["e\u{0301}", "x", "\r\n", "🇷🇺", "🇩"]);
+
+    let mut iter = dataset.graphemes("क्षa");
+
+    assert_eq!(iter.next(), Some("क्ष"));
+    assert_eq!(iter.offset(), "क्ष".len());
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(dataset.graphemes("").next(), None);
+
+    // GB9c, GB11
+    assert_eq!(graphemes("क्ष").count(), 1);
+    assert_eq!(graphemes("👨‍👩‍👧").count(), 1);
+    assert_eq!(graphemes("👶🏿👶").count(), 2);
+
+    let clusters: Vec<&str> = graphemes("Å\u{0328}\u{1100}\u{1161}\u{11A8}!").collect();
+
+    assert_eq!(clusters, ["Å\u{0328}", "\u{1100}\u{1161}\u{11A8}", "!"]);
+}
+
+#[test]
+fn word_and_sentence_break()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    assert_eq!(dataset.word_break(0x41), WordBreak::ALetter);
+    assert_eq!(dataset.word_break(0x0436), WordBreak::ALetter);
+    assert_eq!(dataset.word_break(0x27), WordBreak::SingleQuote);
+    assert_eq!(dataset.word_break(0x05D0), WordBreak::HebrewLetter);
+    assert_eq!(dataset.word_break(0x30A2), WordBreak::Katakana);
+    assert_eq!(dataset.word_break(0x4E00), WordBreak::Other);
+    assert_eq!(dataset.word_break(0x0661), WordBreak::Numeric);
+    assert_eq!(dataset.word_break(0x0301), WordBreak::Extend);
+    assert_eq!(dataset.word_break(0x00AD), WordBreak::Format);
+    assert_eq!(dataset.word_break(0x2019), WordBreak::MidNumLet);
+    assert_eq!(dataset.word_break(0x20), WordBreak::WSegSpace);
+    assert_eq!(dataset.word_break(0xA0), WordBreak::Other);
+    assert_eq!(
+        dataset.unicode()[&0x5F].word_break(dataset),
+        WordBreak::ExtendNumLet
+    );
+    assert_eq!(get_word_break(0x0E01), WordBreak::Other);
+
+    assert_eq!(dataset.sentence_break(0x61), SentenceBreak::Lower);
+    assert_eq!(dataset.sentence_break(0x0416), SentenceBreak::Upper);
+    assert_eq!(dataset.sentence_break(0x2E), SentenceBreak::ATerm);
+    assert_eq!(dataset.sentence_break(0x3002), SentenceBreak::STerm);
+    assert_eq!(dataset.sentence_break(0x29), SentenceBreak::Close);
+    assert_eq!(dataset.sentence_break(0x2029), SentenceBreak::Sep);
+    assert_eq!(dataset.sentence_break(0x4E00), SentenceBreak::OLetter);
+    assert_eq!(
+        dataset.unicode()[&0x41].sentence_break(dataset),
+        SentenceBreak::Upper
+    );
+    assert_eq!(get_sentence_break(0x2C), SentenceBreak::SContinue);
+}
+
+#[test]
+fn word_break_tests()
+{
+    assert_eq!(
+        UnicodeVersion::V15_1_0.dataset().word_break_tests().len(),
+        1826
+    );
+    assert_eq!(
+        UnicodeVersion::V17_0_0.dataset().word_break_tests().len(),
+        1944
+    );
+
+    for version in [UnicodeVersion::V15_1_0, UnicodeVersion::V17_0_0] {
+        let dataset = version.dataset();
+        let report = dataset.word_break_conformance();
+
+        assert_eq!(
+            report.passed,
+            dataset.word_break_tests().len(),
+            "{}",
+            report
+        );
+        assert!(report.is_ok(), "{}", report);
+    }
+}
+
+#[test]
+fn sentence_break_tests()
+{
+    for version in [UnicodeVersion::V15_1_0, UnicodeVersion::V17_0_0] {
+        let dataset = version.dataset();
+        let report = dataset.sentence_break_conformance();
+
+        assert_eq!(dataset.sentence_break_tests().len(), 512);
+        assert_eq!(
+            report.passed,
+            dataset.sentence_break_tests().len(),
+            "{}",
+            report
+        );
+        assert!(report.is_ok(), "{}", report);
+    }
+}
+
+#[test]
+fn words_iterator()
+{
+    let dataset = UnicodeVersion::default().dataset();
+    let text = "Hello, world! 3.14 can't";
+
+    let segments: Vec<&str> = dataset.words(text).collect();
+
+    assert_eq!(
+        segments,
+        ["Hello", ",", " ", "world", "!", " ", "3.14", " ", "can't"]
+    );
+    assert_eq!(segments.concat(), text);
+
+    let segments: Vec<&str> = dataset.words(text).skip_non_words().collect();
+
+    assert_eq!(segments, ["Hello", "world", "3.14", "can't"]);
+    assert_eq!(dataset.words("").next(), None);
+
+    let segments: Vec<&str> = words("Съешь же ещё этих мягких булок, 2,5 кг_м. 你好")
+        .skip_non_words()
+        .collect();
+
+    assert_eq!(
+        segments,
+        [
+            "Съешь",
+            "же",
+            "ещё",
+            "этих",
+            "мягких",
+            "булок",
+            "2,5",
+            "кг_м",
+            "你",
+            "好"
+        ]
+    );
+}
+
+#[test]
+fn sentences_iterator()
+{
+    let dataset = UnicodeVersion::default().dataset();
+    let text = "Hello world. How are you? Fine, e.g. good.\nOk";
+
+    let segments: Vec<&str> = dataset.sentences(text).collect();
+
+    assert_eq!(
+        segments,
+        ["Hello world. ", "How are you? ", "Fine, e.g. good.\n", "Ok"]
+    );
+    assert_eq!(dataset.sentences("").next(), None);
+
+    let segments: Vec<&str> = sentences("Привет. Как дела?! «Хорошо.» 你好。再见").collect();
+
+    assert_eq!(
+        segments,
+        ["Привет. ", "Как дела?! ", "«Хорошо.» ", "你好。", "再见"]
+    );
+}
+
+#[test]
+fn line_break_and_east_asian_width()
+{
+    let dataset = UnicodeVersion::default().dataset();
+
+    assert_eq!(dataset.line_break(0x41), LineBreak::Alphabetic);
+    assert_eq!(dataset.line_break(0x20), LineBreak::Space);
+    assert_eq!(dataset.line_break(0x0661), LineBreak::Numeric);
+    assert_eq!(dataset.line_break(0x0600), LineBreak::Numeric);
+    assert_eq!(dataset.line_break(0x0301), LineBreak::CombiningMark);
+    assert_eq!(dataset.line_break(0x200E), LineBreak::CombiningMark);
+    assert_eq!(dataset.line_break(0x0E01), LineBreak::ComplexContext);
+    assert_eq!(dataset.line_break(0x4E00), LineBreak::Ideographic);
+    assert_eq!(dataset.line_break(0x1100), LineBreak::HangulLJamo);
+    assert_eq!(dataset.line_break(0xAC00), LineBreak::HangulLvSyllable);
+    assert_eq!(dataset.line_break(0xAC01), LineBreak::HangulLvtSyllable);
+    assert_eq!(dataset.line_break(0x20AC), LineBreak::PrefixNumeric);
+    assert_eq!(dataset.line_break(0x2010), LineBreak::UnambiguousHyphen);
+    assert_eq!(dataset.line_break(0x0029), LineBreak::CloseParenthesis);
+    assert_eq!(dataset.line_break(0x00AB), LineBreak::Quotation);
+    assert_eq!(dataset.line_break(0x2028), LineBreak::MandatoryBreak);
+    assert_eq!(dataset.line_break(0x000A), LineBreak::LineFeed);
+    assert_eq!(dataset.line_break(0x000D), LineBreak::CarriageReturn);
+    assert_eq!(dataset.line_break(0x0085), LineBreak::NextLine);
+    assert_eq!(dataset.line_break(0x200B), LineBreak::ZeroWidthSpace);
+    assert_eq!(dataset.line_break(0xFEFF), LineBreak::WordJoiner);
+    assert_eq!(dataset.line_break(0x00A0), LineBreak::Glue);
+    assert_eq!(
+        dataset.line_break(0x3041),
+        LineBreak::ConditionalJapaneseStarter
+    );
+    assert_eq!(dataset.line_break(0x3042), LineBreak::Ideographic);
+    assert_eq!(dataset.line_break(0x1F466), LineBreak::EmojiBase);
+    assert_eq!(dataset.line_break(0xE000), LineBreak::Unknown);
+    assert_eq!(dataset.line_break(0x0378), LineBreak::Unknown);
+    assert_eq!(
+        dataset.unicode()[&0x0E31].line_break(dataset),
+        LineBreak::ComplexContext
+    );
+    assert_eq!(get_line_break(0x2014), LineBreak::BreakBoth);
+
+    assert_eq!(dataset.east_asian_width(0x41), EastAsianWidth::Narrow);
+    assert_eq!(dataset.east_asian_width(0x05D0), EastAsianWidth::Neutral);
+    assert_eq!(dataset.east_asian_width(0x0416), EastAsianWidth::Ambiguous);
+    assert_eq!(dataset.east_asian_width(0x2010), EastAsianWidth::Ambiguous);
+    assert_eq!(dataset.east_asian_width(0x3000), EastAsianWidth::Fullwidth);
+    assert_eq!(dataset.east_asian_width(0x4E00), EastAsianWidth::Wide);
+    assert_eq!(dataset.east_asian_width(0x9FA0), EastAsianWidth::Wide);
+    assert_eq!(dataset.east_asian_width(0xFF21), EastAsianWidth::Fullwidth);
+    assert_eq!(dataset.east_asian_width(0xFF71), EastAsianWidth::Halfwidth);
+    assert_eq!(
+        dataset.unicode()[&0xAC00].east_asian_width(dataset),
+        EastAsianWidth::Wide
+    );
+    assert_eq!(get_east_asian_width(0x1F600), EastAsianWidth::Wide);
+
+    // LB1
+    let resolve = |code: u32| {
+        let gc = dataset.unicode()[&code].gc;
+        dataset.line_break(code).resolve(gc)
+    };
+
+    assert_eq!(resolve(0x0E01), LineBreak::Alphabetic);
+    assert_eq!(resolve(0x0E31), LineBreak::CombiningMark);
+    assert_eq!(resolve(0x3041), LineBreak::Nonstarter);
+    assert_eq!(resolve(0xFFFC), LineBreak::ContingentBreak);
+    assert_eq!(
+        LineBreak::Unknown.resolve(GeneralCategory::Unassigned),
+        LineBreak::Alphabetic
+    );
+}
+
+#[test]
+fn line_break_tests()
+{
+    let dataset = UnicodeVersion::default().dataset();
+    let report = dataset.line_break_conformance();
+
+    assert_eq!(dataset.line_break_tests().len(), 19338);
+    assert_eq!(
+        report.passed,
+        dataset.line_break_tests().len(),
+        "{}",
+        report
+    );
+    assert!(report.is_ok(), "{}", report);
+
+    // LineBreakTest.txt 15.1.0 во встроенных данных нет
+    let dataset = UnicodeVersion::V15_1_0.dataset();
+
+    assert!(!dataset.has_source(SourceFile::LineBreakTest));
+    assert_eq!(
+        dataset.try_line_break_tests().unwrap_err().kind,
+        ParseErrorKind::MissingSource
+    );
+    assert_eq!(
+        dataset.try_line_break_conformance().unwrap_err().kind,
+        ParseErrorKind::MissingSource
+    );
+
+    // правила UAX #14 15.1.0: нет LB15c, LB20a, LB21a для HL (HY | BA) HL
+    let breaks = |version: UnicodeVersion, text| -> Vec<&str> {
+        version
+            .dataset()
+            .line_breaks(text)
+            .map(|(line, _)| line)
+            .collect()
+    };
+
+    assert_eq!(breaks(UnicodeVersion::V15_1_0, "-a"), ["-", "a"]);
+    assert_eq!(breaks(UnicodeVersion::V17_0_0, "-a"), ["-a"]);
+    assert_eq!(breaks(UnicodeVersion::V15_1_0, "a .5"), ["a .5"]);
+    assert_eq!(breaks(UnicodeVersion::V17_0_0, "a .5"), ["a ", ".5"]);
+    assert_eq!(breaks(UnicodeVersion::V15_1_0, "\u{5D0}-\u{5D0}"), ["\u{5D0}-\u{5D0}"]);
+    assert_eq!(breaks(UnicodeVersion::V17_0_0, "\u{5D0}-\u{5D0}"), ["\u{5D0}-", "\u{5D0}"]);
+
+    // набор данных, загруженный на основе встроенного, сохраняет его версию и правила
+    let dataset = DatasetLoader::embedded(UnicodeVersion::V15_1_0).load();
+
+    assert_eq!(dataset.version(), Some(UnicodeVersion::V15_1_0));
+    assert_eq!(
+        dataset.line_breaks("-a").map(|(line, _)| line).collect::<Vec<_>>(),
+        ["-", "a"]
+    );
+}
+
+#[test]
+fn line_breaks_iterator()
+{
+    let dataset = UnicodeVersion::default().dataset();
+    let text = "Hello world,\r\nnext-line (12.5%)";
+
+    let segments: Vec<(&str, BreakOpportunity)> = dataset.line_breaks(text).collect();
+
+    assert_eq!(
+        segments,
+        [
+            ("Hello ", BreakOpportunity::Allowed),
+            ("world,\r\n", BreakOpportunity::Mandatory),
+            ("next-", BreakOpportunity::Allowed),
+            ("line ", BreakOpportunity::Allowed),
+            ("(12.5%)", BreakOpportunity::Mandatory),
+        ]
+    );
+    assert_eq!(dataset.line_breaks("").next(), None);
+
+    let segments: Vec<&str> = line_breaks("Привет, мир! 你好。Ok")
+        .map(|(segment, _)| segment)
+        .collect();
+
+    assert_eq!(segments, ["Привет, ", "мир! ", "你", "好。", "Ok"]);
+}